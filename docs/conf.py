@@ -108,6 +108,7 @@ nitpick_ignore = [
     (PY_CLASS, "CollectionT"),
     (PY_CLASS, "ControllerRouterHandler"),
     (PY_CLASS, "EmptyType"),
+    (PY_CLASS, "EncodedSlashHandling"),
     (PY_CLASS, "ModelT"),
     (PY_CLASS, "PathParameterDefinition"),
     (PY_CLASS, "RouteHandlerType"),
@@ -1,3 +1,4 @@
 from litestar._asgi.asgi_router import ASGIRouter
+from litestar._asgi.tenant_router import TenantASGIRouter
 
-__all__ = ("ASGIRouter",)
+__all__ = ("ASGIRouter", "TenantASGIRouter")
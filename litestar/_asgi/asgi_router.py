@@ -1,30 +1,132 @@
 from __future__ import annotations
 
-import re
-from collections import defaultdict
+import copy
+import unicodedata
+from collections import Counter, defaultdict
+from contextlib import suppress
+from datetime import date, datetime, time, timedelta
 from functools import lru_cache
+from hashlib import sha256
+from pathlib import Path
+from time import perf_counter
 from traceback import format_exc
-from typing import TYPE_CHECKING, Any, Pattern
+from typing import (
+    TYPE_CHECKING,
+    Any,
+    Callable,
+    Iterable,
+    Iterator,
+    Literal,
+    Mapping,
+    NamedTuple,
+    NoReturn,
+    Pattern,
+    cast,
+)
+from urllib.parse import quote, unquote_to_bytes
 
+from typing_extensions import TypeAlias
+
+from litestar._asgi.route_spec import (
+    create_redirect_app,
+    create_route_spec_handler,
+    route_spec_from_description,
+    route_spec_from_starlette,
+)
 from litestar._asgi.routing_trie import validate_node
-from litestar._asgi.routing_trie.mapping import add_route_to_trie
-from litestar._asgi.routing_trie.traversal import parse_path_to_route
-from litestar._asgi.routing_trie.types import create_node
-from litestar._asgi.utils import get_route_handlers
-from litestar.exceptions import ImproperlyConfiguredException
-from litestar.utils import normalize_path
+from litestar._asgi.routing_trie.frozen import find_frozen_route_node, freeze_trie
+from litestar._asgi.routing_trie.mapping import (
+    add_route_to_trie,
+    create_mount_paths_regex,
+    graft_trie,
+    remove_route_from_trie,
+    replace_node_handler,
+)
+from litestar._asgi.routing_trie.traversal import (
+    explain_route_path,
+    find_route_node,
+    get_allowed_methods,
+    get_handler_key,
+    get_route_node,
+    parse_path_params,
+    parse_path_to_route,
+    resolve_node_handler,
+)
+from litestar._asgi.routing_trie.types import (
+    MatchResult,
+    RouteEntry,
+    RouteMatch,
+    RoutingPath,
+    UnreachableRoute,
+    collect_trie_routes,
+    create_node,
+    dump_trie_snapshot,
+    get_trie_stats,
+    render_trie_dot,
+    render_trie_tree,
+    serialize_trie,
+)
+from litestar._asgi.routing_trie.validate import check_trie_invariants, find_unreachable_routes
+from litestar._asgi.utils import (
+    RouteDiff,
+    get_handler_type,
+    get_path_parameter_info,
+    get_route_handler_map,
+    get_route_handlers,
+    get_route_signature,
+    get_route_table_rows,
+    get_scope_path,
+    get_synthetic_path,
+    strip_root_path,
+    warm_up_route_handler,
+)
+from litestar.config.response_cache import create_route_cache_key
+from litestar.enums import ScopeType
+from litestar.exceptions import (
+    ImproperlyConfiguredException,
+    MethodNotAllowedException,
+    NoRouteMatchFoundException,
+    NotFoundException,
+)
+from litestar.status_codes import HTTP_302_FOUND, HTTP_308_PERMANENT_REDIRECT
+from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
+from litestar.utils.module_loader import import_string
+from litestar.utils.path import (
+    canonicalize_path,
+    control_characters_pattern,
+    encoded_slash_pattern,
+    join_paths,
+    normalize_path,
+    normalize_route_path,
+)
+from litestar.utils.scope.state import ScopeState
 
-__all__ = ("ASGIRouter",)
+__all__ = (
+    "ENCODED_SLASH_MARKER",
+    "ASGIRouter",
+    "FrozenASGIRouter",
+    "MatchListener",
+    "MergeConflictPolicy",
+    "RouteEvent",
+    "RouteListener",
+    "RouterSnapshot",
+)
 
 
 if TYPE_CHECKING:
-    from litestar._asgi.routing_trie.types import RouteTrieNode
+    from litestar._asgi.route_spec import RouteSpec, RouteSpecTuple
+    from litestar._asgi.routing_trie.frozen import FrozenRouteTrie, FrozenTrieNode
+    from litestar._asgi.routing_trie.types import RouteTrieNode, TrieStats
+    from litestar._asgi.utils import PathParameterInfo, RouteTableRow
     from litestar.app import Litestar
+    from litestar.handlers.asgi_handlers import ASGIRouteHandler
+    from litestar.handlers.http_handlers import HTTPRouteHandler
     from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
     from litestar.routes.base import BaseRoute
     from litestar.types import (
         ASGIApp,
         LifeSpanReceive,
+        LifeSpanScope,
         LifeSpanSend,
         LifeSpanShutdownCompleteEvent,
         LifeSpanShutdownFailedEvent,
@@ -37,6 +139,48 @@ if TYPE_CHECKING:
         Send,
     )
 
+MatchListener: TypeAlias = "Callable[[str, Method | None, RouteHandlerType], None]"
+MergeConflictPolicy: TypeAlias = Literal["error", "prefer_self", "prefer_other"]
+RouteEvent: TypeAlias = Literal["add", "remove", "replace"]
+RouteListener: TypeAlias = "Callable[[RouteEvent, BaseRoute], None]"
+
+ENCODED_SLASH_MARKER = "\x00"
+"""Stands in for encoded slashes kept within a path segment while routing. Since paths containing control characters
+are never routed, it cannot be confused with a character of the path.
+"""
+
+# path parameters of these types may also be given as strings when building a URL path
+_str_convertible_parameter_types = {datetime, date, time, timedelta, float, Path}
+
+
+def _render_path_parameter(parameter: PathParameterDefinition, path_parameters: dict[str, Any], path: str) -> str:
+    if parameter.name not in path_parameters:
+        raise NoRouteMatchFoundException(f"Missing path parameter {parameter.name} for route {path}")
+    value = path_parameters[parameter.name]
+    if not isinstance(value, parameter.type) and (
+        parameter.type not in _str_convertible_parameter_types or not isinstance(value, str)
+    ):
+        raise NoRouteMatchFoundException(
+            f"Received type for path parameter {parameter.name} doesn't match declared type {parameter.type}"
+        )
+    if parameter.pattern and not parameter.pattern.fullmatch(str(value)):
+        raise NoRouteMatchFoundException(
+            f"Received value for path parameter {parameter.name} doesn't match its constraint "
+            f"{parameter.pattern.pattern!r}"
+        )
+    return str(value)
+
+
+class RouterSnapshot(NamedTuple):
+    """The routing state of a router captured by :meth:`ASGIRouter.snapshot`, to be passed to
+    :meth:`ASGIRouter.restore`.
+    """
+
+    router: ASGIRouter
+    """A copy of the router, holding its own copy of the routing trie."""
+    routes: tuple[BaseRoute, ...]
+    """The routes of the application of the router."""
+
 
 class ASGIRouter:
     """Litestar ASGI router.
@@ -45,12 +189,19 @@ class ASGIRouter:
     """
 
     __slots__ = (
+        "_covered_apps",
+        "_fallback_app",
+        "_grafted_routers",
+        "_match_listeners",
         "_mount_paths_regex",
         "_mount_routes",
         "_plain_routes",
         "_registered_routes",
+        "_route_listeners",
         "_static_routes",
         "app",
+        "deprecated_route_hits",
+        "import_path",
         "root_route_map_node",
         "route_handler_index",
         "route_mapping",
@@ -62,34 +213,380 @@ class ASGIRouter:
         Args:
             app: The Litestar app instance
         """
+        self._covered_apps: set[tuple[int, str | None]] | None = None
+        self._fallback_app: ASGIApp | None = None
+        self._match_listeners: list[MatchListener] = []
         self._mount_paths_regex: Pattern | None = None
         self._mount_routes: dict[str, RouteTrieNode] = {}
+        self._grafted_routers: list[tuple[str, ASGIRouter, MergeConflictPolicy]] = []
         self._plain_routes: set[str] = set()
         self._registered_routes: set[HTTPRoute | WebSocketRoute | ASGIRoute] = set()
+        self._route_listeners: list[RouteListener] = []
         self.app = app
+        self.deprecated_route_hits: Counter[str] = Counter()
+        self.import_path: str | None = None
         self.root_route_map_node: RouteTrieNode = create_node()
         self.route_handler_index: dict[str, RouteHandlerType] = {}
         self.route_mapping: dict[str, list[BaseRoute]] = defaultdict(list)
 
-    async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
+    def __getstate__(self) -> dict[str, Any]:
+        """Return the state of the router for pickling, e.g. to pass it to worker processes or cache it in a fixture.
+
+        Neither the application nor the routing trie, which holds ASGI apps composed from closures, can be pickled.
+        Instead, the application is imported again from :attr:`import_path`, a dotted path such as
+        ``"my_project.app.app"``, when the router is unpickled. The route and match listeners and coverage records are
+        left out, since they are only meaningful within the current process. The fallback app of the router, and any
+        routers mounted on it or merged into it, must be picklable.
+
+        Raises:
+            TypeError: If the router has no :attr:`import_path`.
+        """
+        if self.import_path is None:
+            raise TypeError("Cannot pickle a router without an import path of its application")
+        return {
+            "deprecated_route_hits": self.deprecated_route_hits,
+            "fallback_app": self._fallback_app,
+            "grafted_routers": self._grafted_routers,
+            "import_path": self.import_path,
+        }
+
+    def __setstate__(self, state: dict[str, Any]) -> None:
+        """Restore the router from its pickled state, importing its application and rebuilding the routing trie from
+        the routes of the application and the mounted and merged routers.
+        """
+        ASGIRouter.__init__(self, app=import_string(state["import_path"]))
+        self.import_path = state["import_path"]
+        self.deprecated_route_hits.update(state["deprecated_route_hits"])
+        self._fallback_app = state["fallback_app"]
+        self.construct_routing_trie()
+        for prefix, router, on_conflict in state["grafted_routers"]:
+            self._graft(prefix=prefix, router=router, on_conflict=on_conflict)
+
+    def __copy__(self) -> ASGIRouter:
+        """Copy the router, giving the copy its own routing trie.
+
+        The copy shares the application, as well as the route handlers and ASGI apps stored on the trie. Routers mounted
+        on the copy with :meth:`mount` are therefore not served by this router. Routes registered on the application
+        are added to the application's router though, so to add routes to a copy via the application, see
+        :meth:`clone`.
+        """
+        router = ASGIRouter(app=self.app)
+        graft_trie(
+            mount_routes=router._mount_routes,
+            plain_routes=router._plain_routes,
+            root_node=router.root_route_map_node,
+            prefix="/",
+            other_mount_routes=self._mount_routes,
+            other_plain_routes=self._plain_routes,
+            other_root_node=self.root_route_map_node,
+        )
+        router._fallback_app = self._fallback_app
+        router._mount_paths_regex = self._mount_paths_regex
+        router._grafted_routers = list(self._grafted_routers)
+        router.import_path = self.import_path
+        router._registered_routes = set(self._registered_routes)
+        router._match_listeners = list(self._match_listeners)
+        router._route_listeners = list(self._route_listeners)
+        router.deprecated_route_hits = Counter(self.deprecated_route_hits)
+        router.route_handler_index = dict(self.route_handler_index)
+        router.route_mapping = defaultdict(list, {path: list(routes) for path, routes in self.route_mapping.items()})
+        return router
+
+    def __deepcopy__(self, memo: dict[int, Any]) -> ASGIRouter:
+        """Deep copy the router, along with its application, see :meth:`clone`."""
+        router = ASGIRouter.__new__(ASGIRouter)
+        memo[id(self)] = router
+        for name in ASGIRouter.__slots__:
+            if hasattr(self, name):
+                setattr(router, name, copy.deepcopy(getattr(self, name), memo))
+        # the apps covered so far have been copied, so their records no longer identify them
+        router._covered_apps = None if self._covered_apps is None else set()
+        return router
+
+    def clone(self) -> ASGIRouter:
+        """Create an independent copy of the router and its application, e.g. to add test-only routes to the copy.
+
+        Routes registered on the copy's application, e.g. with :meth:`add_route_spec`, are only served by the copy,
+        leaving this router and its application untouched.
+
+        Returns:
+            The router of the copied application.
+        """
+        return copy.deepcopy(self)
+
+    def freeze(self) -> FrozenASGIRouter:
+        """Create a read-only copy of the router, routing connections via an immutable trie optimized for lookups.
+
+        Once all routes have been registered, the frozen router can be served instead of this router, e.g. wrapped in
+        the exception handling middleware of the application. Routes added to this router afterwards are not served
+        by the frozen router.
+
+        Returns:
+            The frozen router.
+        """
+        return FrozenASGIRouter(router=self)
+
+    def snapshot(self) -> RouterSnapshot:
+        """Capture the routing state of the router, e.g. so that a reloader can roll back a failed batch of changes.
+
+        The routing trie is copied, while the route handlers and ASGI apps stored on it are shared with the snapshot.
+
+        Returns:
+            A snapshot to pass to :meth:`restore`.
+        """
+        return RouterSnapshot(router=ASGIRouter.__copy__(self), routes=tuple(self.app.routes))
+
+    def restore(self, snapshot: RouterSnapshot) -> None:
+        """Restore the routing state captured by :meth:`snapshot`, discarding all changes made since.
+
+        The routes of the application, the routing trie, the mounted and merged routers and the fallback app are
+        restored, and the same snapshot can be restored again later. Route and match listeners, coverage records and
+        deprecated route hits are left as they are, and route listeners are not notified.
+
+        Args:
+            snapshot: A snapshot taken of this router.
+
+        Returns:
+            None
+        """
+        router = ASGIRouter.__copy__(snapshot.router)
+        self._fallback_app = router._fallback_app
+        self._grafted_routers = router._grafted_routers
+        self._mount_paths_regex = router._mount_paths_regex
+        self._mount_routes = router._mount_routes
+        self._plain_routes = router._plain_routes
+        self._registered_routes = router._registered_routes
+        self.root_route_map_node = router.root_route_map_node
+        self.route_handler_index = router.route_handler_index
+        self.route_mapping = router.route_mapping
+        self.app.routes[:] = snapshot.routes
+        self.handle_routing.cache_clear()
+
+    async def __call__(
+        self,
+        scope: Scope | LifeSpanScope,
+        receive: Receive | LifeSpanReceive,
+        send: Send | LifeSpanSend,
+    ) -> None:
         """ASGI callable.
 
-        The main entry point to the Router class.
+        The main entry point to the Router class. Lifespan events are handled by :meth:`lifespan`, allowing the router
+        to be used as a top-level ASGI app.
         """
+        if scope["type"] == "lifespan":
+            await self.lifespan(receive=receive, send=send)  # type: ignore[arg-type]
+            return
+        await self.resolve_asgi_app(scope)(scope, receive, send)  # type: ignore[arg-type]
+
+    def __iter__(self) -> Iterator[RouteEntry]:
+        """Iterate over the routes stored on the routing trie, see :meth:`routes`."""
+        return iter(self.routes())
+
+    def __len__(self) -> int:
+        """Return the number of distinct path templates of the routes stored on the routing trie."""
+        return len({route.path for route in self.routes()})
+
+    def __contains__(self, path: object) -> bool:
+        """Determine whether a request path is routed to a route handler, regardless of the method, e.g. ``"/users/1"
+        in router``.
+
+        Path parameter values are not parsed, so a path may be contained even if its values do not match the types of
+        the path parameters of the route.
+        """
+        if not isinstance(path, str):
+            return False
+        try:
+            self._find_route_node(self._normalize_path(path))
+        except NotFoundException:
+            return False
+        return True
+
+    def resolve_asgi_app(self, scope: Scope) -> ASGIApp:
+        """Resolve the ASGI app serving a connection, see :meth:`resolve`.
+
+        Args:
+            scope: The ASGI connection scope.
+
+        Returns:
+            The ASGI app serving the connection.
+        """
+        return self.resolve(scope).asgi_app
+
+    def resolve(self, scope: Scope) -> MatchResult:
+        """Resolve the ASGI app serving a connection, together with the routing information of the matched route.
+
+        This allows middlewares wrapping the router to use the path template, path parameters and handler of the
+        matched route without routing the path again.
+
+        The matched route handler, the path relative to the matched route, the parsed path parameters and the path
+        template of the matched route are written into the scope as ``route_handler``, ``path``, ``path_params`` and
+        ``route_template``, so that they are available to middlewares, guards and dependencies without another lookup.
+        The path template, e.g. ``/users/{user_id:int}``, identifies the route with a low cardinality, e.g. for metrics
+        and tracing. For mount routes, the mount path is appended to the ``root_path`` of the scope. If versioning is
+        enabled on the application, the requested API version is determined before routing.
+
+        Args:
+            scope: The ASGI connection scope.
+
+        Returns:
+            The match result holding the ASGI app of the matched route, the fallback app set with :meth:`set_fallback`
+            if no route matches, or an app redirecting to the canonical path of the connection if
+            ``redirect_to_canonical_path`` is enabled on the application.
+        """
+        if self.app.redirect_to_canonical_path and scope["type"] == ScopeType.HTTP:
+            if redirect_app := self._create_canonical_path_redirect(scope):
+                return MatchResult(redirect_app, None, {}, {}, None, None)
+
         scope.setdefault("path_params", {})
 
-        path = scope["path"]
-        if root_path := scope.get("root_path", ""):
-            path = path.split(root_path, maxsplit=1)[-1]
-        normalized_path = normalize_path(path)
+        path, keep_encoded_slashes, version, version_prefix = self.get_routing_path(scope)
+        if self.app.versioning_config:
+            ScopeState.from_scope(scope).api_version = version
+            if version_prefix:
+                scope["root_path"] = scope.get("root_path", "") + version_prefix
 
-        asgi_app, scope["route_handler"], scope["path"], scope["path_params"] = self.handle_routing(
-            path=normalized_path, method=scope.get("method")
+        try:
+            asgi_app, route_handler, scope["path"], scope["path_params"], path_template, raw_path_params = (
+                self.handle_routing(path=path, method=scope.get("method"))
+            )
+        except NotFoundException:
+            if self._fallback_app is None:
+                raise
+            return MatchResult(self._fallback_app, None, {}, {}, None, None)
+        if keep_encoded_slashes:
+            replacement = "/" if self.app.encoded_slash_handling == "decode" else "%2F"
+            scope["path"] = scope["path"].replace(ENCODED_SLASH_MARKER, "/")
+            scope["path_params"] = {
+                name: value.replace(ENCODED_SLASH_MARKER, replacement) if isinstance(value, str) else value
+                for name, value in scope["path_params"].items()
+            }
+            raw_path_params = {
+                name: value.replace(ENCODED_SLASH_MARKER, replacement) for name, value in raw_path_params.items()
+            }
+        scope["route_handler"] = route_handler
+        scope["route_template"] = path_template
+        if getattr(route_handler, "is_mount", False):
+            # the mount path is moved into the root path, so that the path is relative to the mounted app
+            scope["root_path"] = scope.get("root_path", "") + path_template.rstrip("/")
+        if getattr(route_handler, "cache", False) and not route_handler.cache_key_builder:  # type: ignore[union-attr]
+            cache_config = self.app.response_cache_config
+            if cache_config.key_from_route:
+                ScopeState.from_scope(scope).cache_key = create_route_cache_key(
+                    scope, path_template=path_template, query_params=cache_config.key_query_params
+                )
+        if getattr(route_handler, "deprecated", False):
+            self.deprecated_route_hits[route_handler.name or str(route_handler)] += 1
+        if self._covered_apps is not None:
+            # a handler serving several methods may share its ASGI app between them
+            self._covered_apps.add((id(asgi_app), scope.get("method")))
+        for listener in self._match_listeners:
+            listener(path_template, scope.get("method"), route_handler)
+        return MatchResult(
+            asgi_app=asgi_app,
+            path_template=path_template,
+            raw_path_params=raw_path_params,
+            path_params=scope["path_params"],
+            handler_type=get_handler_type(route_handler, scope["type"]),
+            handler=route_handler,
         )
-        await asgi_app(scope, receive, send)
+
+    def get_routing_path(self, scope: Scope) -> RoutingPath:
+        """Get the path a connection is routed by, applying the encoded slash handling, unicode normalization and
+        versioning of the application.
+
+        Unlike :meth:`resolve`, this does not modify the scope, so that it can be used to probe whether a route
+        matches a connection.
+
+        Args:
+            scope: The ASGI connection scope.
+
+        Raises:
+            NotFoundException: If the path contains an encoded slash while ``encoded_slash_handling`` is ``"reject"``,
+                or if it contains NUL or other control characters.
+
+        Returns:
+            The routing path, along with the requested API version and the version prefix stripped from it.
+        """
+        encoded_slash_handling = self.app.encoded_slash_handling
+        keep_encoded_slashes = encoded_slash_handling != "split" and bool(
+            encoded_slash_pattern.search((scope.get("raw_path") or b"").split(b"?", 1)[0])
+        )
+        if keep_encoded_slashes and encoded_slash_handling == "reject":
+            raise NotFoundException()
+
+        path = self.get_route_path(
+            scope, keep_encoded_slashes=keep_encoded_slashes, use_raw_path=self.app.route_on_raw_path
+        )
+        if self.app.normalize_unicode_paths:
+            path = unicodedata.normalize("NFC", path)
+        version: str | None = None
+        version_prefix = ""
+        if versioning_config := self.app.versioning_config:
+            version, version_prefix = versioning_config.get_requested_version(scope, path)
+            if version_prefix:
+                path = path[len(version_prefix) :] or "/"
+        return RoutingPath(
+            path=path, keep_encoded_slashes=keep_encoded_slashes, version=version, version_prefix=version_prefix
+        )
+
+    @staticmethod
+    def get_route_path(scope: Scope, keep_encoded_slashes: bool = False, use_raw_path: bool = False) -> str:
+        """Get the normalized path of a connection to route it by.
+
+        Args:
+            scope: The ASGI connection scope.
+            keep_encoded_slashes: Decode the path from the ``raw_path`` of the scope, replacing encoded slashes
+                (``%2F``) with :data:`ENCODED_SLASH_MARKER`, so that they do not separate path segments.
+            use_raw_path: Decode the path from the ``raw_path`` of the scope instead of using the ``path`` decoded by
+                the server. Ignored for scopes without a ``raw_path``.
+
+        Raises:
+            NotFoundException: If the path contains NUL or other control characters.
+
+        Returns:
+            The path of the connection relative to the ``root_path``, normalized, see
+            :func:`strip_root_path <litestar._asgi.utils.strip_root_path>`.
+        """
+        if (keep_encoded_slashes or use_raw_path) and (raw_path := scope.get("raw_path")):
+            # some servers include the query string in raw_path
+            raw_path = raw_path.split(b"?", 1)[0]
+            segments = [
+                unquote_to_bytes(segment).decode("utf-8", errors="replace")
+                for segment in (encoded_slash_pattern.split(raw_path) if keep_encoded_slashes else (raw_path,))
+            ]
+        else:
+            segments = [get_scope_path(scope)]
+        if any(control_characters_pattern.search(segment) for segment in segments):
+            raise NotFoundException()
+        path = ENCODED_SLASH_MARKER.join(segments)
+        return normalize_path(strip_root_path(path, scope.get("root_path", "")))
+
+    def _normalize_path(self, path: str) -> str:
+        path = normalize_path(path)
+        return unicodedata.normalize("NFC", path) if self.app.normalize_unicode_paths else path
+
+    def _normalize_route_path(self, path: str) -> str:
+        path = normalize_route_path(path)
+        return unicodedata.normalize("NFC", path) if self.app.normalize_unicode_paths else path
+
+    @staticmethod
+    def _create_canonical_path_redirect(scope: Scope) -> ASGIApp | None:
+        from litestar.response.base import ASGIResponse
+
+        root_path = scope.get("root_path", "")
+        path = strip_root_path(get_scope_path(scope), root_path)
+        if path in ("", canonical_path := canonicalize_path(path)):
+            return None
+
+        location = quote(root_path + canonical_path)
+        if query_string := scope.get("query_string", b""):
+            location += f"?{query_string.decode('latin-1')}"
+        return ASGIResponse(status_code=HTTP_308_PERMANENT_REDIRECT, headers={"location": location})
 
     @lru_cache(1024)  # noqa: B019
-    def handle_routing(self, path: str, method: Method | None) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any]]:
+    def handle_routing(
+        self, path: str, method: Method | None
+    ) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any], str, dict[str, str]]:
         """Handle routing for a given path / method combo. This method is meant to allow easy caching.
 
         Args:
@@ -97,7 +594,7 @@ class ASGIRouter:
             method: The scope's method, if any.
 
         Returns:
-            A tuple composed of the ASGIApp of the route, the route handler instance, the resolved and normalized path and any parsed path params.
+            A tuple composed of the ASGIApp of the route, the route handler instance, the resolved and normalized path, any parsed path params, the path template of the route and the raw path params.
         """
         return parse_path_to_route(
             mount_paths_regex=self._mount_paths_regex,
@@ -106,6 +603,102 @@ class ASGIRouter:
             plain_routes=self._plain_routes,
             root_node=self.root_route_map_node,
             method=method,
+            head_falls_back_to_get=self.app.head_falls_back_to_get,
+        )
+
+    def _find_route_node(self, path: str) -> tuple[RouteTrieNode | FrozenTrieNode, list[str], str]:
+        return find_route_node(
+            mount_paths_regex=self._mount_paths_regex,
+            mount_routes=self._mount_routes,
+            path=path,
+            plain_routes=self._plain_routes,
+            root_node=self.root_route_map_node,
+        )
+
+    def match(self, path: str, method: Method | None = None) -> RouteMatch | None:
+        """Find the route a request would be routed to, without resolving its ASGI app or parsing path parameters.
+
+        This is meant for tests, middlewares and link checkers, which need routing information without a connection.
+
+        Args:
+            path: A request path.
+            method: The HTTP method of the request. Websocket and ASGI routes are matched if not given.
+
+        Returns:
+            The matched route, or ``None`` if no route serves the path and method, or if the values of its path
+            parameters cannot be parsed.
+        """
+        try:
+            node, path_parameters, _ = self._find_route_node(self._normalize_path(path))
+        except NotFoundException:
+            return None
+
+        key = get_handler_key(node=node, method=method, head_falls_back_to_get=self.app.head_falls_back_to_get)
+        if key not in node.asgi_handlers:
+            return None
+        try:
+            # the values are returned unparsed, but must be parseable for the route to serve the path
+            parse_path_params(node.path_parameters[key], tuple(path_parameters))
+        except ValueError:
+            return None
+
+        return RouteMatch(
+            path_template=node.path_templates[key],
+            path_params={
+                definition.name: value for definition, value in zip(node.path_parameters[key], path_parameters)
+            },
+            handler_type=key if key in ("websocket", "asgi") else "http",  # type: ignore[arg-type]
+            handler=node.asgi_handlers[key].handler,
+            opt=node.asgi_handlers[key].handler.opt,
+        )
+
+    def metadata_for(self, path: str, method: Method | None = None) -> dict[str, Any] | None:
+        """Retrieve the metadata of the route a request would be routed to, i.e. the ``opt`` of its route handler.
+
+        Metadata such as tags or the team owning a route can be attached via the ``opt`` of a route handler, router or
+        :class:`RouteSpec <litestar._asgi.route_spec.RouteSpec>`, e.g. for observability middlewares to read.
+
+        Args:
+            path: A request path.
+            method: The HTTP method of the request. Websocket and ASGI routes are matched if not given.
+
+        Returns:
+            The metadata of the matched route, or ``None`` if no route serves the path and method.
+        """
+        return route_match.opt if (route_match := self.match(path, method)) else None
+
+    def allowed_methods(self, path: str) -> set[str]:
+        """Determine the HTTP methods a request path is served for, e.g. to answer CORS preflight requests.
+
+        Args:
+            path: A request path.
+
+        Returns:
+            The HTTP methods of the route the path is routed to, including ``HEAD`` if ``HEAD`` requests fall back to
+            the ``GET`` handler. Empty if the path is not routed or only served by websocket or ASGI route handlers.
+        """
+        try:
+            node, _, _ = self._find_route_node(self._normalize_path(path))
+        except NotFoundException:
+            return set()
+        return get_allowed_methods(node, head_falls_back_to_get=self.app.head_falls_back_to_get)
+
+    def explain(self, path: str) -> list[dict[str, Any]]:
+        """Describe how a request path is routed, step by step, e.g. to debug why a path is not found.
+
+        Args:
+            path: A request path.
+
+        Returns:
+            A list of the steps taken, see :func:`explain_route_path
+            <litestar._asgi.routing_trie.traversal.explain_route_path>`.
+        """
+        return explain_route_path(
+            mount_paths_regex=self._mount_paths_regex,
+            mount_routes=self._mount_routes,
+            path=self._normalize_path(path),
+            plain_routes=self._plain_routes,
+            root_node=self.root_route_map_node,
         )
 
     def _store_handler_to_route_mapping(self, route: BaseRoute) -> None:
@@ -127,12 +720,26 @@ class ASGIRouter:
             self.route_mapping[identifier].append(route)
             self.route_handler_index[identifier] = handler
 
+    def _remove_handler_to_route_mapping(self, route: BaseRoute) -> None:
+        for handler in get_route_handlers(route):
+            identifier = handler.name or str(handler)
+            routes = self.route_mapping.get(identifier, [])
+            if route in routes:
+                routes.remove(route)
+            if not routes:
+                self.route_mapping.pop(identifier, None)
+                self.route_handler_index.pop(identifier, None)
+
     def construct_routing_trie(self) -> None:
         """Create a map of the app's routes.
 
         This map is used in the asgi router to route requests.
         """
         new_routes = [route for route in self.app.routes if route not in self._registered_routes]
+        current_routes = set(self.app.routes)
+        replaced_routes = {
+            (route.path, route.scope_type): route for route in self._registered_routes if route not in current_routes
+        }
         for route in new_routes:
             add_route_to_trie(
                 app=self.app,
@@ -143,10 +750,740 @@ class ASGIRouter:
             )
             self._store_handler_to_route_mapping(route)
             self._registered_routes.add(route)
+            if replaced_route := replaced_routes.pop((route.path, route.scope_type), None):
+                self._registered_routes.discard(replaced_route)
+                self._notify_route_listeners("replace", route)
+            else:
+                self._notify_route_listeners("add", route)
 
         validate_node(node=self.root_route_map_node)
         if self._mount_routes:
-            self._mount_paths_regex = re.compile("|".join(sorted(set(self._mount_routes))))  # pyright: ignore
+            self._mount_paths_regex = create_mount_paths_regex(self._mount_routes)
+        # paths resolved before may now be served by a new or replacing route
+        self.handle_routing.cache_clear()
+
+    def add_route_listener(self, listener: RouteListener) -> None:
+        """Register a callback invoked whenever a route is added to, removed from or replaced in the router.
+
+        The callback receives the kind of change and the affected route. For replacements, the new route is passed.
+
+        Args:
+            listener: A callable receiving a :data:`RouteEvent` and a route.
+
+        Returns:
+            None
+        """
+        self._route_listeners.append(listener)
+
+    def _notify_route_listeners(self, event: RouteEvent, route: BaseRoute) -> None:
+        for listener in self._route_listeners:
+            listener(event, route)
+
+    def set_fallback(self, app: ASGIApp | None) -> None:
+        """Set an ASGI app serving connections no route matches, e.g. the index page of a single page application, a
+        reverse proxy or a custom "not found" page, instead of raising a
+        :class:`NotFoundException <litestar.exceptions.NotFoundException>`.
+
+        Connections matching a route, but not any of its methods, are still rejected.
+
+        Args:
+            app: The fallback ASGI app, or ``None`` to remove the fallback.
+
+        Returns:
+            None
+        """
+        self._fallback_app = app
+
+    def add_match_listener(self, listener: MatchListener) -> None:
+        """Register a callback invoked whenever a connection is routed, e.g. for metrics or audit logging.
+
+        The callback is invoked by :meth:`resolve_asgi_app` before the ASGI app of the matched route is returned, and
+        receives the path template of the route, the method of the connection, or ``None`` for websockets, and the
+        matched route handler.
+
+        Args:
+            listener: A callable receiving a path template, a method and a route handler.
+
+        Returns:
+            None
+        """
+        self._match_listeners.append(listener)
+
+    def add_route_spec(self, spec: RouteSpec | RouteSpecTuple) -> ASGIRouteHandler:
+        """Register an ASGI app described by a plain dict or tuple.
+
+        This bypasses the route handler decorators, allowing routes to be registered from data, e.g. a generated or
+        deserialized route table. The app is registered as an ASGI route handler on the application.
+
+        Args:
+            spec: A :class:`RouteSpec <litestar._asgi.route_spec.RouteSpec>` or a ``(path, methods, handler)`` tuple.
+
+        Raises:
+            ImproperlyConfiguredException: If the declared path parameters do not match the path.
+
+        Returns:
+            The ASGI route handler created for the spec.
+        """
+        route_handler = create_route_spec_handler(spec)
+        self.app.register(route_handler)
+        return route_handler
+
+    def add_redirect(
+        self, from_path: str, to_path: str, status_code: int = HTTP_302_FOUND, name: str | None = None
+    ) -> ASGIRouteHandler:
+        """Register a route redirecting HTTP requests to another path or URL, e.g. for renamed endpoints.
+
+        Path parameters matched by ``from_path`` are carried over to placeholders of the same name in ``to_path``, e.g.
+        ``router.add_redirect("/users/{user_id:int}", "/accounts/{user_id}", 301)``.
+
+        Args:
+            from_path: The path template to redirect from.
+            to_path: The path template or URL to redirect to.
+            status_code: The redirect status code.
+            name: A unique name for the redirect route.
+
+        Raises:
+            ImproperlyConfiguredException: If ``status_code`` is not a redirect status code, or ``to_path`` uses a
+                placeholder not declared in ``from_path``.
+
+        Returns:
+            The ASGI route handler created for the redirect.
+        """
+        spec: RouteSpec = {
+            "path": from_path,
+            "handler": create_redirect_app(from_path=from_path, to_path=to_path, status_code=status_code),
+        }
+        if name is not None:
+            spec["name"] = name
+        return self.add_route_spec(spec)
+
+    def add_routes(self, routes: Iterable[RouteSpec | RouteSpecTuple | Any]) -> list[ASGIRouteHandler]:
+        """Register several routes described as dicts, tuples or Starlette routes.
+
+        Starlette ``Route``, ``WebSocketRoute`` and ``Mount`` instances are translated into route specs, allowing
+        applications migrating from Starlette to reuse their routing tables.
+
+        Args:
+            routes: An iterable of :class:`RouteSpec <litestar._asgi.route_spec.RouteSpec>`, ``(path, methods,
+                handler)`` tuples or Starlette routes.
+
+        Returns:
+            The ASGI route handlers created for the routes.
+        """
+        return [
+            self.add_route_spec(route if isinstance(route, (dict, tuple)) else route_spec_from_starlette(route))
+            for route in routes
+        ]
+
+    def remove_route(self, path: str, method: Method | None = None) -> None:
+        """Deregister the routes of a path at runtime, e.g. when unloading a plugin.
+
+        Trie nodes left without handlers are pruned. If ``method`` is given, only the handlers of that method are
+        removed from the HTTP route of the path, which is updated in place, and the route is removed entirely once no
+        other handlers are left. Handlers serving several methods are removed for all of them.
+
+        Args:
+            path: The path template of the route, e.g. ``/users/{user_id:int}``.
+            method: The HTTP method to remove. If not given, all routes of the path are removed.
+
+        Raises:
+            NoRouteMatchFoundException: If no route of the path handles ``method``.
+
+        Returns:
+            None
+        """
+        from litestar.routes import HTTPRoute
+
+        path = self._normalize_route_path(path)
+        routes = [
+            route
+            for route in self.app.routes
+            if route.path == path and (method is None or (isinstance(route, HTTPRoute) and method in route.methods))
+        ]
+        if not routes:
+            raise NoRouteMatchFoundException(f"No route found for {f'{method} ' if method else ''}{path!r}")
+
+        for route in routes:
+            if isinstance(route, HTTPRoute) and method is not None:
+                removed_handlers = [handler for handler in route.route_handlers if method in handler.http_methods]
+                if any(
+                    handler not in removed_handlers and handler is not route.options_handler
+                    for handler in route.route_handlers
+                ):
+                    # only the keys of the removed handlers are dropped from the trie, the other handlers keep their
+                    # middleware stacks, which refer to the route
+                    keys = {http_method for handler in removed_handlers for http_method in handler.http_methods}
+                    remove_route_from_trie(
+                        mount_routes=self._mount_routes,
+                        plain_routes=self._plain_routes,
+                        root_node=self.root_route_map_node,
+                        route=route,
+                        keys=keys,
+                    )
+                    self._remove_handler_to_route_mapping(route)
+                    self._remove_route_methods(route=route, route_handlers=removed_handlers, methods=keys)
+                    self._store_handler_to_route_mapping(route)
+                    self._notify_route_listeners("replace", route)
+                    continue
+
+            remove_route_from_trie(
+                mount_routes=self._mount_routes,
+                plain_routes=self._plain_routes,
+                root_node=self.root_route_map_node,
+                route=route,
+            )
+            self._remove_handler_to_route_mapping(route)
+            self._registered_routes.discard(route)
+            self.app.routes.remove(route)
+            self._notify_route_listeners("remove", route)
+
+        self._mount_paths_regex = create_mount_paths_regex(self._mount_routes) if self._mount_routes else None
+        self.handle_routing.cache_clear()
+
+    @staticmethod
+    def _remove_route_methods(route: HTTPRoute, route_handlers: list[HTTPRouteHandler], methods: set[Method]) -> None:
+        route.route_handlers = [handler for handler in route.route_handlers if handler not in route_handlers]
+        if route.options_handler in route_handlers:
+            route.options_handler = None
+        route.handler_names = [handler.handler_name for handler in route.route_handlers]
+        route.methods.difference_update(methods)
+        for handler_map in (
+            route.route_handler_map,
+            route.media_type_handler_map,
+            route.host_handler_map,
+            route.version_handler_map,
+        ):
+            for http_method in methods:
+                handler_map.pop(http_method, None)
+
+    def replace_handler(self, path: str, method: Method, new_handler: HTTPRouteHandler) -> HTTPRouteHandler:
+        """Swap the route handler serving a method of a path at runtime, e.g. to reload an endpoint during development.
+
+        Only the middleware stack of the new handler is built, the routing trie and the other handlers of the route
+        are left as they are. The new handler is registered in place of the old one, inheriting the layered
+        configuration of its router or controller, and replaces it for all methods the old handler serves.
+
+        Args:
+            path: The path template of the route, e.g. ``/users/{user_id:int}``.
+            method: An HTTP method served by the handler to replace.
+            new_handler: The new route handler. It must serve the same HTTP methods as the handler it replaces, while
+                the path it is declared with is ignored.
+
+        Raises:
+            NoRouteMatchFoundException: If no route of the path handles ``method``.
+            ImproperlyConfiguredException: If ``method`` is dispatched to several handlers by media type, host or API
+                version, or if the new handler does not serve the same methods as the handler it replaces.
+
+        Returns:
+            The registered copy of the new route handler.
+        """
+        from litestar.routes import HTTPRoute
+
+        path = self._normalize_route_path(path)
+        route = next(
+            (
+                route
+                for route in self.app.routes
+                if isinstance(route, HTTPRoute) and route.path == path and method in route.route_handler_map
+            ),
+            None,
+        )
+        if route is None:
+            raise NoRouteMatchFoundException(f"No route found for {method} {path!r}")
+        dispatch_maps = (route.media_type_handler_map, route.host_handler_map, route.version_handler_map)
+        if any(method in handler_map for handler_map in dispatch_maps):
+            raise ImproperlyConfiguredException(
+                f"The handlers of {method} {path!r} are dispatched by media type, host or API version and cannot be "
+                "replaced"
+            )
+
+        old_handler = route.route_handler_map[method][0]
+        if set(new_handler.http_methods) != set(old_handler.http_methods):
+            raise ImproperlyConfiguredException(
+                f"Handler {new_handler} must serve the methods {', '.join(sorted(old_handler.http_methods))} of the "
+                f"handler {old_handler} it replaces"
+            )
+
+        route_handler = copy.copy(new_handler)
+        route_handler.owner = old_handler.owner
+        route_handler.on_registration(self.app)
+        # the route is replaced rather than changed, since the middleware stacks of its other handlers refer to it
+        new_route = HTTPRoute(
+            path=route.path,
+            route_handlers=[route_handler if handler is old_handler else handler for handler in route.route_handlers],
+        )
+        new_route.options_handler = None if route.options_handler is old_handler else route.options_handler
+        new_route.create_handler_map()
+        for plugin in self.app.plugins.receive_route:
+            plugin.receive_route(new_route)
+
+        node = get_route_node(root_node=self.root_route_map_node, mount_routes=self._mount_routes, route=route)
+        replace_node_handler(
+            app=self.app, route=new_route, node=cast("RouteTrieNode", node), route_handler=route_handler
+        )
+        self._remove_handler_to_route_mapping(route)
+        self._store_handler_to_route_mapping(new_route)
+        self._registered_routes.discard(route)
+        self._registered_routes.add(new_route)
+        self.app.routes[self.app.routes.index(route)] = new_route
+        self._notify_route_listeners("replace", new_route)
+        self.handle_routing.cache_clear()
+        return route_handler
+
+    def mount(self, prefix: str, router: ASGIRouter) -> None:
+        """Serve the routes of another router under a path prefix, e.g. to compose independently built applications.
+
+        The routing trie of the other router is grafted under the prefix. Its routes are served by their existing
+        handlers, including the middleware and other settings of their own application, and are listed by
+        :meth:`routes` and matched by :meth:`match` with the prefix prepended. They are not added to the routes of this
+        router's application, so they cannot be removed with :meth:`remove_route` or reversed with
+        :meth:`url_path_for`. Routes registered on the other router afterwards are not mounted.
+
+        Args:
+            prefix: The path prefix, e.g. ``/billing``. Path parameters are not supported.
+            router: The router whose routes are mounted.
+
+        Raises:
+            ImproperlyConfiguredException: If the prefix contains path parameters, or if a route of the other router
+                conflicts with a route of this router.
+
+        Returns:
+            None
+        """
+        prefix = self._normalize_route_path(prefix)
+        if "{" in prefix:
+            raise ImproperlyConfiguredException(f"Mount prefix {prefix!r} must not contain path parameters")
+        self._graft(prefix=prefix, router=router, on_conflict="error")
+
+    def merge(self, other: ASGIRouter, on_conflict: MergeConflictPolicy = "error") -> None:
+        """Combine the routes of another router with the routes of this router, e.g. to layer the routes of plugins.
+
+        This is equivalent to mounting the other router under ``/``, see :meth:`mount`, except that conflicts can be
+        resolved.
+
+        Args:
+            other: The router whose routes are merged.
+            on_conflict: What to do if both routers handle the same method of the same path: raise an exception, keep
+                the handler of this router or use the handler of the other router.
+
+        Raises:
+            ImproperlyConfiguredException: If a route of the other router conflicts with a route of this router and
+                ``on_conflict`` is ``"error"``.
+
+        Returns:
+            None
+        """
+        self._graft(prefix="/", router=other, on_conflict=on_conflict)
+
+    def _graft(self, prefix: str, router: ASGIRouter, on_conflict: MergeConflictPolicy) -> None:
+        graft_trie(
+            mount_routes=self._mount_routes,
+            plain_routes=self._plain_routes,
+            root_node=self.root_route_map_node,
+            prefix=prefix,
+            other_mount_routes=router._mount_routes,
+            other_plain_routes=router._plain_routes,
+            other_root_node=router.root_route_map_node,
+            on_conflict=on_conflict,
+        )
+        validate_node(node=self.root_route_map_node)
+        self._mount_paths_regex = create_mount_paths_regex(self._mount_routes) if self._mount_routes else None
+        self._grafted_routers.append((prefix, router, on_conflict))
+        self.handle_routing.cache_clear()
+
+    def route_for_name(self, name: str) -> RouteEntry | None:
+        """Look up the route of a named route handler, e.g. for configuration keyed by route name.
+
+        Args:
+            name: A route handler unique name.
+
+        Returns:
+            The path template of the route, the keys the handler is stored under on the routing trie, i.e. HTTP methods,
+            ``"websocket"`` or ``"asgi"``, and the handler, or ``None`` if no route handler is registered with ``name``.
+            If the handler is registered on several paths, the route registered first is returned.
+        """
+        if (route_handler := self.route_handler_index.get(name)) is None:
+            return None
+        for route in self.route_mapping[name]:
+            node = get_route_node(
+                root_node=self.root_route_map_node,
+                mount_routes=self._mount_routes,
+                route=route,  # type: ignore[arg-type]
+            )
+            if node is None:
+                continue
+            keys = tuple(sorted(key for key, (_, handler) in node.asgi_handlers.items() if handler is route_handler))
+            if keys:
+                return RouteEntry(path=node.path_templates[keys[0]], methods=keys, handler=route_handler)
+        return None
+
+    def url_path_for(self, name: str, **path_parameters: Any) -> str:
+        """Build the URL path of a named route handler from the path templates stored when its routes were registered.
+
+        If the handler is registered on several paths, the path with the most path parameters that are all given is
+        used.
+
+        Args:
+            name: A route handler unique name.
+            **path_parameters: Values for the path parameters of the route.
+
+        Raises:
+            NoRouteMatchFoundException: If no route handler is registered with ``name``, or if a path parameter is
+                missing, has a type not matching its declaration or a value not matching its constraint.
+
+        Returns:
+            A fully formatted URL path.
+        """
+        route_handler = self.route_handler_index.get(name)
+        if route_handler is None:
+            raise NoRouteMatchFoundException(f"Route {name} can not be found")
+
+        routes = sorted(
+            self.route_mapping[route_handler.name or str(route_handler)],
+            key=lambda r: len(r.path_parameters),
+            reverse=True,
+        )
+        passed_parameters = set(path_parameters)
+        selected_route = next(
+            (route for route in routes if passed_parameters.issuperset(p.name for p in route.path_parameters)),
+            routes[-1],
+        )
+
+        output: list[str] = []
+        for component in selected_route.path_components:
+            if isinstance(component, PathSegmentPattern):
+                output.append(
+                    component.render(
+                        _render_path_parameter(parameter, path_parameters, selected_route.path)
+                        for parameter in component.parameters
+                    )
+                )
+            elif isinstance(component, PathParameterDefinition):
+                output.append(_render_path_parameter(component, path_parameters, selected_route.path))
+            else:
+                output.append(component)
+
+        return join_paths(output)
+
+    def openapi_paths(self) -> dict[str, tuple[Method, ...]]:
+        """Return the OpenAPI path templates of the registered HTTP routes.
+
+        Path parameters are rendered as ``{name}`` placeholders, without their types.
+
+        Returns:
+            A mapping of path templates to the sorted HTTP methods they accept.
+        """
+        paths: dict[str, set[Method]] = defaultdict(set)
+        for route in self.app.routes:
+            if route.scope_type == ScopeType.HTTP:
+                paths[route.path_format or "/"].update(route.methods)
+        return {path: tuple(sorted(methods)) for path, methods in paths.items()}
+
+    def path_parameter_info(self) -> dict[str, tuple[PathParameterInfo, ...]]:
+        """Return structured information about the path parameters of the registered routes.
+
+        This complements :meth:`openapi_paths`, allowing parameter documentation to be generated from the routing table.
+
+        Returns:
+            A mapping of route paths to their path parameters.
+        """
+        return {route.path: get_path_parameter_info(route) for route in self.app.routes}
+
+    def routes(self) -> list[RouteEntry]:
+        """Enumerate the routes stored on the routing trie, e.g. for admin dashboards or debugging.
+
+        The routes are reconstructed from the trie rather than taken from the application, so they reflect what
+        requests are actually routed to.
+
+        Returns:
+            A list of ``(path template, methods, handler)`` tuples, sorted by path template and methods.
+        """
+        return collect_trie_routes(self.root_route_map_node)
+
+    def stats(self) -> TrieStats:
+        """Compute statistics of the routing trie, e.g. for capacity planning of very large route tables.
+
+        Returns:
+            The node count, maximum depth, placeholder count and estimated memory usage of the trie.
+        """
+        return get_trie_stats(self.root_route_map_node)
+
+    def table(self, sort_by: Literal["path", "name", "methods"] = "path") -> list[RouteTableRow]:
+        """Return a table of the registered route handlers, e.g. for display by the ``litestar routes`` command.
+
+        Args:
+            sort_by: The column to sort the rows by. Ties are broken by path.
+
+        Raises:
+            ValueError: If ``sort_by`` is not a supported column.
+
+        Returns:
+            A row for each route handler of each registered route.
+        """
+        sort_keys: dict[str, Callable[[RouteTableRow], Any]] = {
+            "path": lambda row: (row.path, row.methods),
+            "name": lambda row: (row.name or "", row.path),
+            "methods": lambda row: (row.methods, row.path),
+        }
+        if sort_by not in sort_keys:
+            raise ValueError(f"Cannot sort route table by {sort_by!r}, expected one of {', '.join(sort_keys)}")
+        rows = [row for route in self.app.routes for row in get_route_table_rows(route)]
+        return sorted(rows, key=sort_keys[sort_by])
+
+    def referrers_report(self) -> dict[str, list[object]]:
+        """Return the objects kept alive by the router for each registered route.
+
+        This is meant as a debugging aid when hunting memory leaks in processes registering routes dynamically.
+
+        Returns:
+            A mapping of route paths to the ASGI apps, route handlers and handler functions the routing trie references.
+        """
+        report: dict[str, list[object]] = {}
+        for route in sorted(self._registered_routes, key=lambda r: (r.path, str(r.scope_type))):
+            node = get_route_node(root_node=self.root_route_map_node, mount_routes=self._mount_routes, route=route)
+            retained = report.setdefault(route.path, [])
+            for key in get_route_handler_map(route):
+                if node is None or key not in node.asgi_handlers:
+                    continue
+                asgi_app, route_handler = node.asgi_handlers[key]
+                for obj in (asgi_app, route_handler, route_handler.fn):
+                    if not any(obj is other for other in retained):
+                        retained.append(obj)
+        return report
+
+    def dump_snapshot(self) -> str:
+        """Render the routing trie as a stable, sorted text dump, e.g. for golden file tests.
+
+        Returns:
+            The text dump of the routing trie.
+        """
+        return dump_trie_snapshot(
+            root_node=self.root_route_map_node, mount_routes=self._mount_routes, plain_routes=self._plain_routes
+        )
+
+    def render_tree(self) -> str:
+        """Render the routing trie as an indented tree of path components and the methods served at each node.
+
+        Returns:
+            The rendered tree, e.g. for printing while debugging the routing of a large application.
+        """
+        return render_trie_tree(root_node=self.root_route_map_node, mount_routes=self._mount_routes)
+
+    def to_dot(self) -> str:
+        """Render the routing trie as a Graphviz DOT graph, annotating each node with the methods served at it.
+
+        Returns:
+            The DOT source, e.g. to render with ``dot -Tsvg`` for documentation or architecture reviews.
+        """
+        return render_trie_dot(root_node=self.root_route_map_node, mount_routes=self._mount_routes)
+
+    def to_json(self) -> str:
+        """Serialize the routing trie to JSON, e.g. for tooling diffing deployed route tables or configuring gateways.
+
+        Handlers and ASGI apps are left out. The output is stable, sorted by path template.
+
+        Returns:
+            A JSON object with the keys ``routes``, a list of objects with the ``path`` template, ``methods``,
+            ``handlers`` and ``path_parameters`` of each route, ``plain_routes``, a list of the paths of routes without
+            path parameters, and ``mount_routes``, a list of objects with the ``path`` and ``is_static`` flag of each
+            mount route. ``handlers`` maps the methods of a route to the names of their handlers, see
+            :meth:`from_spec`.
+        """
+        from litestar.serialization import encode_json
+
+        return encode_json(
+            serialize_trie(
+                root_node=self.root_route_map_node, mount_routes=self._mount_routes, plain_routes=self._plain_routes
+            )
+        ).decode()
+
+    @classmethod
+    def from_spec(
+        cls,
+        spec: str | bytes | Mapping[str, Any],
+        handler_lookup: Callable[[str], ASGIApp | None],
+        app: Litestar | None = None,
+    ) -> ASGIRouter:
+        """Rebuild a routing table serialized by :meth:`to_json`, e.g. to start quickly from a precomputed route table.
+
+        Each route is registered as a route spec, see :meth:`add_route_spec`, serving the ASGI apps the names of its
+        handlers are mapped to.
+
+        Args:
+            spec: The serialized routing table, as a JSON document or the dictionary it decodes to.
+            handler_lookup: A callable mapping the name of a handler to the ASGI app serving its requests. Handlers it
+                returns ``None`` for, e.g. the ``OPTIONS`` handlers generated by Litestar, are left out.
+            app: The application to register the routes on. Defaults to a new application without OpenAPI
+                documentation.
+
+        Returns:
+            The router of the application.
+        """
+        from litestar.app import Litestar
+        from litestar.serialization import decode_json
+
+        if app is None:
+            app = Litestar(openapi_config=None)
+        if isinstance(spec, (str, bytes)):
+            spec = decode_json(spec)
+        mount_paths = {mount_route["path"] for mount_route in spec["mount_routes"]}
+        for description in spec["routes"]:
+            route_spec = route_spec_from_description(
+                description, handler_lookup=handler_lookup, is_mount=description["path"] in mount_paths
+            )
+            if route_spec is not None:
+                app.asgi_router.add_route_spec(route_spec)
+        return app.asgi_router
+
+    def _route_signatures(self) -> dict[tuple[str, str], tuple[str, ...]]:
+        # routes differing only in the types of their path parameters share a path format
+        grouped_signatures: defaultdict[tuple[str, str], list[str]] = defaultdict(list)
+        for route in self._registered_routes:
+            grouped_signatures[(route.path_format, route.scope_type.value)].append(get_route_signature(route))
+        return {key: tuple(sorted(signatures)) for key, signatures in grouped_signatures.items()}
+
+    def version(self) -> str:
+        """Compute a stable hash of the routing table.
+
+        The hash covers the path templates, HTTP methods and scope types of the registered routes, as well as which
+        paths are mount and static paths. It does not depend on the order routes were registered in, so it can be
+        compared across instances to detect routing drift, or used to invalidate caches when routing changes.
+
+        Returns:
+            The hex digest of the routing table.
+        """
+        signatures = sorted(get_route_signature(route) for route in self._registered_routes)
+        return sha256("\n".join(signatures).encode()).hexdigest()
+
+    def diff(self, other: ASGIRouter) -> RouteDiff:
+        """Compare the routing table to the routing table of another router.
+
+        Routes serving the same scope type under the same path, disregarding the types of path parameters, are
+        considered the same route. If they differ in their methods or parameters, they are reported as changed. If
+        several routes share such a path, e.g. ``GET /items/{id:int}`` and ``DELETE /items/{id:str}``, the routes not
+        present in both routers are paired up as changed in the order of their signatures, and any remaining ones are
+        reported as added or removed.
+
+        Args:
+            other: The router to compare to.
+
+        Returns:
+            The routes added, removed and changed in ``other`` compared to this router, sorted by path.
+        """
+        own, others = self._route_signatures(), other._route_signatures()
+        route_diff = RouteDiff(added=[], removed=[], changed=[])
+        for key in sorted(own.keys() | others.keys()):
+            own_signatures, other_signatures = own.get(key, ()), others.get(key, ())
+            if own_signatures == other_signatures:
+                continue
+            removed = [signature for signature in own_signatures if signature not in other_signatures]
+            added = [signature for signature in other_signatures if signature not in own_signatures]
+            route_diff.changed.extend(zip(removed, added))
+            route_diff.removed.extend(removed[len(added) :])
+            route_diff.added.extend(added[len(removed) :])
+        return route_diff
+
+    def enable_coverage(self) -> None:
+        """Start recording which routes are resolved, discarding any previous records.
+
+        See :meth:`coverage_report`.
+
+        Returns:
+            None
+        """
+        self._covered_apps = set()
+
+    def coverage_report(self, ignore_methods: Iterable[str] = ("OPTIONS",)) -> list[str]:
+        """List the routes that have not been resolved since :meth:`enable_coverage` was called.
+
+        This allows test suites to assert that a request has been made against each of the app's endpoints.
+
+        Args:
+            ignore_methods: HTTP methods to leave out of the report. By default, the automatically created ``OPTIONS``
+                handlers are left out.
+
+        Raises:
+            ImproperlyConfiguredException: If coverage tracking has not been enabled.
+
+        Returns:
+            The untouched routes as ``"<method> <path>"`` entries, using ``websocket`` or ``asgi`` as the method of
+            websocket and ASGI routes.
+        """
+        if self._covered_apps is None:
+            raise ImproperlyConfiguredException("Route coverage tracking has not been enabled")
+
+        ignored = set(ignore_methods)
+        report: list[str] = []
+        for route in sorted(self._registered_routes, key=lambda r: (r.path, str(r.scope_type))):
+            node = get_route_node(root_node=self.root_route_map_node, mount_routes=self._mount_routes, route=route)
+            for key in sorted(get_route_handler_map(route)):
+                if key in ignored or node is None or key not in node.asgi_handlers:
+                    continue
+                app_id = id(node.asgi_handlers[key].asgi_app)
+                if not any(
+                    covered_id == app_id and (covered_method == key or key in ("websocket", "asgi", "*"))
+                    for covered_id, covered_method in self._covered_apps
+                ):
+                    report.append(f"{key} {route.path}")
+        return report
+
+    def self_check(self) -> list[str]:
+        """Verify the structural invariants of the routing trie.
+
+        This is meant as a debugging aid, e.g. after routes have been registered dynamically.
+
+        Returns:
+            A list of descriptions of violated invariants. Empty if the trie is consistent.
+        """
+        return check_trie_invariants(
+            root_node=self.root_route_map_node,
+            mount_routes=self._mount_routes,
+            plain_routes=self._plain_routes,
+            routes=self.app.routes,
+        )
+
+    def validate(self) -> list[UnreachableRoute]:
+        """Find the registered routes that no request can ever be routed to.
+
+        Such routes are not rejected when they are registered, since they do not break the routing of other routes,
+        but they usually indicate a mistake, e.g. a route registered below the path of a static files mount. This is
+        meant to be called once all routes have been added, e.g. in a test or at startup.
+
+        Returns:
+            A list of unreachable routes together with the reason they cannot be matched. Empty if all routes can be
+            reached.
+        """
+        versioning_config = self.app.versioning_config
+        return find_unreachable_routes(
+            root_node=self.root_route_map_node,
+            mount_routes=self._mount_routes,
+            routes=self.app.routes,
+            version_prefixes=versioning_config.versions if versioning_config and versioning_config.path_prefix else (),
+        )
+
+    def warm_up(self) -> dict[str, float]:
+        """Prepare all registered routes for handling requests, e.g. before a worker starts accepting connections.
+
+        Computes the memoized values of the route handlers, such as their response handlers, and populates the routing
+        cache by resolving a request path rendered from each route's template.
+
+        Returns:
+            A mapping of route paths to the time it took to warm them up, in seconds.
+        """
+        report: dict[str, float] = {}
+        for route in sorted(self._registered_routes, key=lambda r: (r.path, str(r.scope_type))):
+            start = perf_counter()
+            for route_handler in get_route_handlers(route):
+                warm_up_route_handler(route_handler)
+
+            path = get_synthetic_path(route)
+            methods: list[Method | None] = sorted(route.methods) if route.scope_type == ScopeType.HTTP else [None]
+            for method in methods:
+                # the placeholder values may not be accepted by custom path parameter types
+                with suppress(NotFoundException, MethodNotAllowedException):
+                    self.handle_routing(path=path, method=method)
+            report[route.path] = report.get(route.path, 0) + perf_counter() - start
+        return report
 
     async def lifespan(self, receive: LifeSpanReceive, send: LifeSpanSend) -> None:
         """Handle the ASGI "lifespan" event on application startup and shutdown.
@@ -182,3 +1519,106 @@ class ASGIRouter:
             raise e
 
         await send(shutdown_event)
+
+
+class FrozenASGIRouter(ASGIRouter):
+    """A read-only Litestar ASGI router, see :meth:`ASGIRouter.freeze`.
+
+    Connections are routed via a :class:`FrozenRouteTrie <litestar._asgi.routing_trie.frozen.FrozenRouteTrie>`, while
+    introspection methods such as :meth:`routes` use the routing trie the router has been frozen from.
+    """
+
+    __slots__ = ("frozen_trie",)
+
+    def __init__(self, router: ASGIRouter) -> None:
+        """Initialize ``FrozenASGIRouter``.
+
+        Args:
+            router: The router to freeze. It is copied, so that it remains mutable.
+        """
+        router = copy.copy(router)
+        for name in ASGIRouter.__slots__:
+            if hasattr(router, name):
+                setattr(self, name, getattr(router, name))
+        self.frozen_trie: FrozenRouteTrie = freeze_trie(
+            root_node=self.root_route_map_node,
+            mount_routes=self._mount_routes,
+            plain_routes=self._plain_routes,
+            mount_paths_regex=self._mount_paths_regex,
+        )
+
+    def __reduce__(self) -> tuple[Any, ...]:
+        """Pickle the router as the mutable router it has been frozen from, freezing it again when unpickled."""
+        return FrozenASGIRouter, (self._thaw(),)
+
+    def __copy__(self) -> FrozenASGIRouter:
+        """Return the router itself, since it cannot be modified."""
+        return self
+
+    def __deepcopy__(self, memo: dict[int, Any]) -> FrozenASGIRouter:
+        """Deep copy the router, along with its application."""
+        return FrozenASGIRouter(router=copy.deepcopy(self._thaw(), memo))
+
+    def _thaw(self) -> ASGIRouter:
+        return ASGIRouter.__copy__(self)
+
+    @lru_cache(1024)  # noqa: B019
+    def handle_routing(
+        self, path: str, method: Method | None
+    ) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any], str, dict[str, str]]:
+        """Handle routing for a given path / method combo via the frozen trie.
+
+        Args:
+            path: The path of the request.
+            method: The scope's method, if any.
+
+        Returns:
+            A tuple composed of the ASGIApp of the route, the route handler instance, the resolved and normalized path,
+            any parsed path params, the path template of the route and the raw path params.
+        """
+        try:
+            node, path_parameters, path = find_frozen_route_node(trie=self.frozen_trie, path=path)
+        except KeyError as e:
+            raise MethodNotAllowedException() from e
+        return resolve_node_handler(
+            node=node,
+            path_parameters=path_parameters,
+            path=path,
+            method=method,
+            head_falls_back_to_get=self.app.head_falls_back_to_get,
+        )
+
+    def _find_route_node(self, path: str) -> tuple[RouteTrieNode | FrozenTrieNode, list[str], str]:
+        return find_frozen_route_node(trie=self.frozen_trie, path=path)
+
+    @staticmethod
+    def _raise_frozen() -> NoReturn:
+        raise ImproperlyConfiguredException("Routes cannot be changed on a frozen router")
+
+    def construct_routing_trie(self) -> None:
+        """Raise, since the routes of a frozen router cannot be changed."""
+        self._raise_frozen()
+
+    def add_route_spec(self, spec: RouteSpec | RouteSpecTuple) -> ASGIRouteHandler:
+        """Raise, since the routes of a frozen router cannot be changed."""
+        self._raise_frozen()
+
+    def remove_route(self, path: str, method: Method | None = None) -> None:
+        """Raise, since the routes of a frozen router cannot be changed."""
+        self._raise_frozen()
+
+    def mount(self, prefix: str, router: ASGIRouter) -> None:
+        """Raise, since the routes of a frozen router cannot be changed."""
+        self._raise_frozen()
+
+    def merge(self, other: ASGIRouter, on_conflict: MergeConflictPolicy = "error") -> None:
+        """Raise, since the routes of a frozen router cannot be changed."""
+        self._raise_frozen()
+
+    def replace_handler(self, path: str, method: Method, new_handler: HTTPRouteHandler) -> HTTPRouteHandler:
+        """Raise, since the routes of a frozen router cannot be changed."""
+        self._raise_frozen()
+
+    def restore(self, snapshot: RouterSnapshot) -> None:
+        """Raise, since the routes of a frozen router cannot be changed."""
+        self._raise_frozen()
@@ -0,0 +1,240 @@
+from __future__ import annotations
+
+import re
+from typing import TYPE_CHECKING, Any, Callable, Mapping, Sequence, cast
+from urllib.parse import quote
+
+from typing_extensions import NotRequired, TypeAlias, TypedDict
+
+from litestar.exceptions import ImproperlyConfiguredException, MethodNotAllowedException, NotFoundException
+
+__all__ = (
+    "RouteSpec",
+    "RouteSpecTuple",
+    "create_redirect_app",
+    "create_route_spec_handler",
+    "route_spec_from_description",
+    "route_spec_from_starlette",
+)
+
+
+if TYPE_CHECKING:
+    from litestar.handlers.asgi_handlers import ASGIRouteHandler
+    from litestar.types import ASGIApp, Method, Receive, Scope, Send
+
+
+class RouteSpec(TypedDict):
+    """A plain description of a route, serving an ASGI app."""
+
+    path: str
+    """The path of the route, e.g. ``/items/{item_id:int}``."""
+    handler: ASGIApp
+    """The ASGI app serving the route."""
+    methods: NotRequired[Sequence[Method]]
+    """The HTTP methods the route accepts. If not given, all methods and websocket connections are accepted."""
+    name: NotRequired[str]
+    """A unique name for the route."""
+    path_parameters: NotRequired[Sequence[str]]
+    """The names of the path parameters expected in ``path``. If given, they are checked against the path."""
+    is_mount: NotRequired[bool]
+    """Whether the route is a mount, serving any path below ``path``."""
+    opt: NotRequired[Mapping[str, Any]]
+    """Metadata of the route, e.g. tags or the team owning it, set as the ``opt`` of its route handler."""
+
+
+RouteSpecTuple: TypeAlias = "tuple[str, Sequence[Method] | None, ASGIApp]"
+"""A route described as a ``(path, methods, handler)`` tuple."""
+
+_starlette_placeholder_regex = re.compile(r"{(\w+)}")
+_starlette_convertor_types = {
+    "FloatConvertor": "float",
+    "IntegerConvertor": "int",
+    "PathConvertor": "path",
+    "StringConvertor": "str",
+    "UUIDConvertor": "uuid",
+}
+
+
+def create_route_spec_handler(spec: RouteSpec | RouteSpecTuple) -> ASGIRouteHandler:
+    """Create an ASGI route handler for a route spec.
+
+    Args:
+        spec: A :class:`RouteSpec` or a ``(path, methods, handler)`` tuple.
+
+    Raises:
+        ImproperlyConfiguredException: If the declared path parameters do not match the path.
+
+    Returns:
+        An ASGI route handler.
+    """
+    from litestar.handlers.asgi_handlers import asgi
+    from litestar.routes.base import param_match_regex
+
+    if isinstance(spec, tuple):
+        path, methods, asgi_app = spec
+        spec = {"path": path, "handler": asgi_app}
+        if methods is not None:
+            spec["methods"] = methods
+
+    if "path_parameters" in spec:
+        placeholders = {p.split(":")[0].strip() for p in param_match_regex.findall(spec["path"])}
+        if placeholders != set(spec["path_parameters"]):
+            raise ImproperlyConfiguredException(
+                f"Path parameters {sorted(spec['path_parameters'])} of route spec do not match the placeholders "
+                f"{sorted(placeholders)} in its path {spec['path']!r}"
+            )
+
+    allowed_methods = {method.upper() for method in spec["methods"]} if "methods" in spec else None
+    asgi_app = spec["handler"]
+
+    async def route_spec_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        if allowed_methods is not None and scope.get("method") not in allowed_methods:
+            raise MethodNotAllowedException(allowed_methods=allowed_methods)
+        await asgi_app(scope, receive, send)
+
+    return asgi(
+        spec["path"], name=spec.get("name"), is_mount=spec.get("is_mount", False), opt=spec.get("opt")
+    )(route_spec_handler)
+
+
+def create_redirect_app(from_path: str, to_path: str, status_code: int) -> ASGIApp:
+    """Create an ASGI app redirecting HTTP requests to a path or URL.
+
+    Placeholders in ``to_path``, e.g. ``{user_id}`` or ``{user_id:int}``, are replaced with the values of the path
+    parameters of the same name matched by ``from_path``. The query string of the request is carried over.
+
+    Args:
+        from_path: The path template of the redirect route, e.g. ``/users/{user_id:int}``.
+        to_path: The path template or URL to redirect to, e.g. ``/accounts/{user_id}``.
+        status_code: The redirect status code, e.g. ``301``.
+
+    Raises:
+        ImproperlyConfiguredException: If ``status_code`` is not a redirect status code, or ``to_path`` uses a
+            placeholder not declared in ``from_path``.
+
+    Returns:
+        An ASGI app sending the redirect response.
+    """
+    from litestar.response.base import ASGIResponse
+    from litestar.routes.base import segment_param_match_regex
+    from litestar.utils.path import multi_slash_pattern
+
+    if not 300 <= status_code < 400:
+        raise ImproperlyConfiguredException(f"{status_code} is not a redirect status code")
+
+    declared = {p.split(":")[0].strip() for p in segment_param_match_regex.findall(from_path)}
+    if undeclared := {p.split(":")[0].strip() for p in segment_param_match_regex.findall(to_path)} - declared:
+        raise ImproperlyConfiguredException(
+            f"Redirect target {to_path!r} uses path parameters {sorted(undeclared)} not declared in {from_path!r}"
+        )
+    is_url = "://" in to_path
+
+    async def redirect_app(scope: Scope, receive: Receive, send: Send) -> None:
+        if scope["type"] != "http":
+            raise NotFoundException()
+        path_params = scope["path_params"]
+        location = segment_param_match_regex.sub(
+            lambda match: quote(str(path_params[match.group(1).split(":")[0].strip()]), safe="/"), to_path
+        )
+        if not is_url:
+            # values of 'path' type parameters start with a slash
+            location = scope.get("root_path", "") + multi_slash_pattern.sub("/", location)
+        if query_string := scope.get("query_string", b""):
+            location += f"?{query_string.decode('latin-1')}"
+        await ASGIResponse(status_code=status_code, headers={"location": location})(scope, receive, send)
+
+    return redirect_app
+
+
+def route_spec_from_starlette(route: Any) -> RouteSpec:
+    """Translate a Starlette ``Route``, ``WebSocketRoute`` or ``Mount`` into a route spec.
+
+    Starlette placeholders (``{item_id:int}``) are mapped to the equivalent Litestar path parameter types.
+
+    Args:
+        route: A Starlette route.
+
+    Raises:
+        ImproperlyConfiguredException: If the route is not a supported Starlette route, or uses a convertor without a
+            Litestar equivalent.
+
+    Returns:
+        A route spec.
+    """
+    route_type = type(route).__name__
+    if not type(route).__module__.startswith("starlette") or route_type not in {"Route", "WebSocketRoute", "Mount"}:
+        raise ImproperlyConfiguredException(f"Cannot create a route from {route!r}")
+
+    def to_placeholder(match: re.Match) -> str:
+        name = match.group(1)
+        convertor = type(route.param_convertors[name]).__name__
+        if convertor not in _starlette_convertor_types:
+            raise ImproperlyConfiguredException(
+                f"Starlette convertor {convertor} of parameter {name!r} in path {route.path!r} is not supported"
+            )
+        return f"{{{name}:{_starlette_convertor_types[convertor]}}}"
+
+    spec: RouteSpec = {
+        "path": _starlette_placeholder_regex.sub(to_placeholder, route.path_format),
+        "handler": route.app,
+    }
+    if route.name:
+        spec["name"] = route.name
+    if route_type == "Mount":
+        spec["is_mount"] = True
+    elif route_type == "Route" and route.methods:
+        spec["methods"] = sorted(route.methods)
+    return spec
+
+
+def route_spec_from_description(
+    description: Mapping[str, Any], handler_lookup: Callable[[str], ASGIApp | None], is_mount: bool = False
+) -> RouteSpec | None:
+    """Create a route spec from the description of a route serialized by
+    :meth:`ASGIRouter.to_json <litestar._asgi.asgi_router.ASGIRouter.to_json>`.
+
+    Requests are dispatched to the app of the handler stored under their method, or under ``"websocket"`` for
+    websocket connections, falling back to the handler stored under ``"asgi"``.
+
+    Args:
+        description: The description of a route.
+        handler_lookup: A callable mapping the name of a handler to the ASGI app serving its requests. Handlers it
+            returns ``None`` for are left out.
+        is_mount: Whether the route is a mount route.
+
+    Returns:
+        A route spec, or ``None`` if none of the handlers of the route are mapped to an ASGI app.
+    """
+    apps = {
+        key: asgi_app
+        for key, name in description["handlers"].items()
+        if (asgi_app := handler_lookup(name)) is not None
+    }
+    if not apps:
+        return None
+
+    http_methods = cast("list[Method]", sorted(key for key in apps if key not in {"websocket", "asgi"}))
+    handler = next(iter(apps.values()))
+    if any(asgi_app is not handler for asgi_app in apps.values()):
+        handler = _create_dispatching_app(apps=apps, allowed_methods=http_methods)
+
+    spec: RouteSpec = {
+        "path": description["path"],
+        "handler": handler,
+        "path_parameters": [parameter["name"] for parameter in description["path_parameters"]],
+    }
+    if is_mount:
+        spec["is_mount"] = True
+    elif http_methods and len(http_methods) == len(apps):
+        spec["methods"] = http_methods
+    return spec
+
+
+def _create_dispatching_app(apps: dict[str, ASGIApp], allowed_methods: Sequence[Method]) -> ASGIApp:
+    async def dispatching_app(scope: Scope, receive: Receive, send: Send) -> None:
+        key = scope.get("method", "") if scope["type"] == "http" else "websocket"
+        if (asgi_app := apps.get(key) or apps.get("asgi")) is None:
+            raise MethodNotAllowedException(allowed_methods=allowed_methods)
+        await asgi_app(scope, receive, send)
+
+    return dispatching_app
@@ -0,0 +1,307 @@
+from __future__ import annotations
+
+import sys
+from bisect import bisect_left
+from types import MappingProxyType
+from typing import TYPE_CHECKING, Mapping, NamedTuple, Pattern
+
+from litestar._asgi.routing_trie.types import PathParameterSentinel
+from litestar.exceptions import NotFoundException
+from litestar.utils import normalize_path
+
+__all__ = (
+    "FrozenRouteTrie",
+    "FrozenTrieNode",
+    "find_frozen_route_node",
+    "freeze_trie",
+    "get_frozen_child",
+    "traverse_frozen_trie",
+)
+
+
+if TYPE_CHECKING:
+    from litestar._asgi.routing_trie.types import ASGIHandlerTuple, RouteTrieNode
+    from litestar.types import Method
+    from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
+
+
+class FrozenTrieNode(NamedTuple):
+    """An immutable routing trie node, see :func:`freeze_trie`."""
+
+    asgi_handlers: Mapping[Method | str, ASGIHandlerTuple]
+    """A read-only mapping of ASGI handlers stored on the node."""
+    path_parameters: Mapping[Method | str, tuple[PathParameterDefinition, ...]]
+    """A read-only mapping of the keys of the handlers to the path parameter definitions of their routes."""
+    path_templates: Mapping[Method | str, str]
+    """A read-only mapping of the keys of the handlers to the path templates of their routes."""
+    child_keys: tuple[str, ...]
+    """The sorted, interned literal path components of the children."""
+    children: tuple[FrozenTrieNode, ...]
+    """The children matching the literal path components, in the order of ``child_keys``."""
+    child_key_suffixes: tuple[tuple[str, ...], ...]
+    """The further literal path components leading to the children, in the order of ``child_keys``.
+
+    Chains of nodes that can only be left via a single literal path component are compressed into one edge, so that
+    e.g. the child of the key ``api`` is the node of ``/api/v1/users`` if ``v1`` and ``users`` are the only components
+    following ``api``.
+    """
+    segment_patterns: tuple[tuple[PathSegmentPattern, FrozenTrieNode], ...]
+    """The segment patterns of the children together with the children, in the order in which they are tried."""
+    placeholder: FrozenTrieNode | None
+    """The node reached by consuming ``placeholder_depth`` path parameters, if any."""
+    placeholder_depth: int
+    """The number of consecutive path parameters leading to ``placeholder``."""
+    is_asgi: bool
+    """Designate the node as having an `asgi` type handler."""
+    is_mount: bool
+    """Designate the node as being a mount route."""
+    is_static: bool
+    """Designate the node as being a static mount route."""
+    is_path_type: bool
+    """Designates the node as having a 'path' type path parameter."""
+
+
+class FrozenRouteTrie(NamedTuple):
+    """An immutable routing trie, together with the plain and mount routes stored on it."""
+
+    root: FrozenTrieNode
+    """The root node."""
+    plain_routes: Mapping[str, FrozenTrieNode]
+    """A read-only mapping of the paths of routes without path parameters to their nodes."""
+    mount_routes: Mapping[str, FrozenTrieNode]
+    """A read-only mapping of mount paths to their nodes."""
+    mount_paths_regex: Pattern | None
+    """A compiled regex to match the mount routes."""
+
+
+def _is_placeholder_link(node: RouteTrieNode) -> bool:
+    # a node that can only be left via another path parameter, so that a chain of such nodes can be skipped at once
+    return (
+        not node.asgi_handlers
+        and not node.is_path_type
+        and not node.is_mount
+        and node.children.keys() == {PathParameterSentinel}
+    )
+
+
+def _is_literal_link(node: RouteTrieNode) -> bool:
+    # a node that can only be left via a single literal path component, so that a chain of such nodes can be compressed
+    return (
+        not node.asgi_handlers
+        and not node.is_path_type
+        and not node.is_mount
+        and len(node.children) == 1
+        and isinstance(next(iter(node.children)), str)
+    )
+
+
+def _freeze_node(node: RouteTrieNode, frozen_nodes: dict[int, FrozenTrieNode]) -> FrozenTrieNode:
+    if (frozen_node := frozen_nodes.get(id(node))) is not None:
+        return frozen_node
+
+    child_keys = sorted(key for key in node.children if isinstance(key, str))
+    placeholder: FrozenTrieNode | None = None
+    placeholder_depth = 0
+    if node.is_path_param_node:
+        placeholder_node = node.children[PathParameterSentinel]
+        placeholder_depth = 1
+        while _is_placeholder_link(placeholder_node):
+            placeholder_node = placeholder_node.children[PathParameterSentinel]
+            placeholder_depth += 1
+        placeholder = _freeze_node(placeholder_node, frozen_nodes)
+
+    children: list[FrozenTrieNode] = []
+    child_key_suffixes: list[tuple[str, ...]] = []
+    for key in child_keys:
+        child_node = node.children[key]
+        suffix: list[str] = []
+        while _is_literal_link(child_node):
+            next_key = next(iter(child_node.children))
+            suffix.append(sys.intern(next_key))
+            child_node = child_node.children[next_key]
+        children.append(_freeze_node(child_node, frozen_nodes))
+        child_key_suffixes.append(tuple(suffix))
+
+    frozen_node = frozen_nodes[id(node)] = FrozenTrieNode(
+        asgi_handlers=MappingProxyType(dict(node.asgi_handlers)),
+        path_parameters=MappingProxyType(dict(node.path_parameters)),
+        path_templates=MappingProxyType(dict(node.path_templates)),
+        child_keys=tuple(sys.intern(key) for key in child_keys),
+        children=tuple(children),
+        child_key_suffixes=tuple(child_key_suffixes),
+        segment_patterns=tuple(
+            (segment_pattern, _freeze_node(node.children[segment_pattern], frozen_nodes))
+            for segment_pattern in node.segment_patterns
+        ),
+        placeholder=placeholder,
+        placeholder_depth=placeholder_depth,
+        is_asgi=node.is_asgi,
+        is_mount=node.is_mount,
+        is_static=node.is_static,
+        is_path_type=node.is_path_type,
+    )
+    return frozen_node
+
+
+def freeze_trie(
+    root_node: RouteTrieNode,
+    mount_routes: dict[str, RouteTrieNode],
+    plain_routes: set[str],
+    mount_paths_regex: Pattern | None,
+) -> FrozenRouteTrie:
+    """Convert a routing trie into an immutable representation optimized for lookups.
+
+    The children of each node are stored as sorted arrays of interned path components, which are searched by bisection.
+    Like in a radix tree, chains of nodes that can only be left via a single literal path component are compressed into
+    one edge, and chains of nodes that can only be left via a path parameter are flattened into a single step. Nodes
+    reachable by multiple paths, such as the nodes of mount routes, are frozen once and shared.
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: Mapping of mount routes to trie nodes.
+        plain_routes: The set of plain routes.
+        mount_paths_regex: A compiled regex to match the mount routes.
+
+    Returns:
+        The frozen trie.
+    """
+    frozen_nodes: dict[int, FrozenTrieNode] = {}
+    root = _freeze_node(root_node, frozen_nodes)
+    return FrozenRouteTrie(
+        root=root,
+        plain_routes=MappingProxyType(
+            {sys.intern(path): _freeze_node(root_node.children[path], frozen_nodes) for path in sorted(plain_routes)}
+        ),
+        mount_routes=MappingProxyType(
+            {path: _freeze_node(node, frozen_nodes) for path, node in sorted(mount_routes.items())}
+        ),
+        mount_paths_regex=mount_paths_regex,
+    )
+
+
+def _get_child_index(node: FrozenTrieNode, component: str) -> int | None:
+    child_keys = node.child_keys
+    index = bisect_left(child_keys, component)
+    if index < len(child_keys) and child_keys[index] == component:
+        return index
+    return None
+
+
+def get_frozen_child(node: FrozenTrieNode, component: str) -> FrozenTrieNode | None:
+    """Retrieve the child of a frozen node whose edge starts with a literal path component.
+
+    Args:
+        node: The frozen trie node.
+        component: A path component.
+
+    Returns:
+        The child node, or ``None`` if the node has no child for the component.
+    """
+    index = _get_child_index(node, component)
+    return None if index is None else node.children[index]
+
+
+def _match_segment_patterns(node: FrozenTrieNode, component: str) -> tuple[FrozenTrieNode, tuple[str, ...]] | None:
+    for segment_pattern, child in node.segment_patterns:
+        if match := segment_pattern.pattern.fullmatch(component):
+            return child, match.groups()
+    return None
+
+
+def traverse_frozen_trie(root: FrozenTrieNode, path: str) -> tuple[FrozenTrieNode, list[str], str]:
+    """Traverse a frozen routing trie and retrieve the correct node for the request url.
+
+    Like :func:`traverse_route_map <litestar._asgi.routing_trie.traversal.traverse_route_map>`, literal path components
+    take precedence over segment patterns, which take precedence over placeholders.
+
+    Args:
+        root: The root node of the frozen trie.
+        path: The request's path.
+
+    Raises:
+        NotFoundException: If no correlating node is found.
+
+    Returns:
+        A tuple containing the target node and a list containing all path parameter values.
+    """
+    current_node = root
+    path_params: list[str] = []
+    path_components = [p for p in path.split("/") if p]
+    component_count = len(path_components)
+    i = 0
+
+    while i < component_count:
+        component = path_components[i]
+        if (index := _get_child_index(current_node, component)) is not None:
+            # the components of a compressed edge must all match, since the nodes skipped by it cannot be left otherwise
+            if suffix := current_node.child_key_suffixes[index]:
+                end = i + 1 + len(suffix)
+                if tuple(path_components[i + 1 : end]) != suffix:
+                    raise NotFoundException()
+                i = end
+            else:
+                i += 1
+            current_node = current_node.children[index]
+            continue
+
+        if current_node.segment_patterns and (segment_match := _match_segment_patterns(current_node, component)):
+            current_node, values = segment_match
+            path_params.extend(values)
+            i += 1
+            continue
+
+        if current_node.placeholder is not None:
+            # the components leading to the last path parameter of a flattened chain, which may be of 'path' type
+            last = i + current_node.placeholder_depth - 1
+            if last >= component_count:
+                raise NotFoundException()
+            current_node = current_node.placeholder
+            path_params.extend(path_components[i:last])
+
+            if current_node.is_path_type:
+                path_params.append(normalize_path("/".join(path_components[last:])))
+                break
+
+            path_params.append(path_components[last])
+            i = last + 1
+            continue
+
+        raise NotFoundException()
+
+    if not current_node.asgi_handlers:
+        raise NotFoundException()
+
+    return current_node, path_params, path
+
+
+def find_frozen_route_node(trie: FrozenRouteTrie, path: str) -> tuple[FrozenTrieNode, list[str], str]:
+    """Find the node of a frozen trie serving a request path, see
+    :func:`find_route_node <litestar._asgi.routing_trie.traversal.find_route_node>`.
+
+    Args:
+        trie: The frozen trie.
+        path: The request path.
+
+    Raises:
+        NotFoundException: If no correlating node is found.
+
+    Returns:
+        A tuple containing the node, the raw path parameter values and the path relative to the matched route.
+    """
+    if (plain_node := trie.plain_routes.get(path)) is not None:
+        return plain_node, [], path
+
+    if trie.mount_paths_regex and (match := trie.mount_paths_regex.match(path)):
+        mount_path = match.group(0) or "/"
+        mount_node = trie.mount_routes[mount_path]
+        remaining_path = path[match.end() :]
+        # since we allow regular handlers under static paths, we must validate that the request does not match
+        # any such handler.
+        next_component = remaining_path.split("/", 2)[1] if remaining_path else None
+        if next_component is None or get_frozen_child(mount_node, next_component) is None:
+            remaining_path = remaining_path or "/"
+            if not mount_node.is_static:
+                remaining_path = remaining_path if remaining_path.endswith("/") else f"{remaining_path}/"
+            return mount_node, [], remaining_path
+
+    return traverse_frozen_trie(root=trie.root, path=path)
@@ -1,24 +1,45 @@
 from __future__ import annotations
 
+import re
+import sys
 from pathlib import Path
-from typing import TYPE_CHECKING, Any, cast
+from typing import TYPE_CHECKING, Any, Iterable, Literal, Pattern, cast
+from urllib.parse import urlencode
 
+from litestar._asgi.routing_trie.traversal import get_route_node
 from litestar._asgi.routing_trie.types import (
     ASGIHandlerTuple,
     PathParameterSentinel,
     create_node,
 )
-from litestar._asgi.utils import wrap_in_exception_handler
-from litestar.types.internal_types import PathParameterDefinition
-
-__all__ = ("add_mount_route", "add_route_to_trie", "build_route_middleware_stack", "configure_node")
+from litestar._asgi.routing_trie.validate import validate_placeholder_type, validate_route_path_parameters
+from litestar._asgi.utils import create_route_conflict_exception, get_route_handler_map, wrap_in_exception_handler
+from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
+
+__all__ = (
+    "add_mount_route",
+    "add_route_to_trie",
+    "build_cached_middleware_stack",
+    "build_route_middleware_stack",
+    "configure_node",
+    "create_host_dispatcher",
+    "create_media_type_dispatcher",
+    "create_version_dispatcher",
+    "create_mount_paths_regex",
+    "get_route_cors_config",
+    "graft_trie",
+    "remove_route_from_trie",
+    "replace_node_handler",
+)
 
 
 if TYPE_CHECKING:
     from litestar._asgi.routing_trie.types import RouteTrieNode
     from litestar.app import Litestar
+    from litestar.config.cors import CORSConfig
+    from litestar.handlers.http_handlers import HTTPRouteHandler
     from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
-    from litestar.types import ASGIApp, RouteHandlerType
+    from litestar.types import ASGIApp, Method, Receive, RouteHandlerType, Scope, Send
 
 
 def add_mount_route(
@@ -59,6 +80,27 @@ def add_mount_route(
     return current_node
 
 
+def create_mount_paths_regex(mount_routes: dict[str, RouteTrieNode]) -> Pattern:
+    """Compile a regex matching any of the given mount paths as a prefix of a request path.
+
+    The mount paths are matched as whole path segments anchored at the start of the path, so that ``/static`` matches
+    ``/static`` and ``/static/app.js``, but neither ``/static-files`` nor ``/files/static``. Longer mount paths are
+    tried first so that the most specific mount wins.
+
+    Args:
+        mount_routes: A dictionary mapping mount paths to trie nodes.
+
+    Returns:
+        A compiled regex.
+    """
+    mount_paths = sorted((path for path in mount_routes if path != "/"), key=lambda p: (-len(p), p))
+    alternatives = [re.escape(path) for path in mount_paths]
+    if "/" in mount_routes:
+        # an empty alternative matches the root mount, which is a prefix of every path
+        alternatives.append("")
+    return re.compile(f"^(?:{'|'.join(alternatives)})(?=/|$)")
+
+
 def add_route_to_trie(
     app: Litestar,
     mount_routes: dict[str, RouteTrieNode],
@@ -80,9 +122,15 @@ def add_route_to_trie(
         root_node: The root trie node.
         route: The route that is being added.
 
+    Raises:
+        ImproperlyConfiguredException: If the route's path parameters do not match its path template, or if a
+            parameter of type ``path`` shares its position with a parameter of another type.
+
     Returns:
         A RouteTrieNode instance.
     """
+    validate_route_path_parameters(route)
+
     current_node = root_node
 
     has_path_parameters = bool(route.path_parameters)
@@ -104,10 +152,13 @@ def add_route_to_trie(
     else:
         for component in route.path_components:
             if isinstance(component, PathParameterDefinition):
+                validate_placeholder_type(node=current_node, parameter=component, route=route)
                 current_node.is_path_param_node = True
-                next_node_key: type[PathParameterSentinel] | str = PathParameterSentinel
+                next_node_key: type[PathParameterSentinel] | PathSegmentPattern | str = PathParameterSentinel
 
             else:
+                if isinstance(component, PathSegmentPattern) and component not in current_node.segment_patterns:
+                    current_node.segment_patterns.append(component)
                 next_node_key = component
 
             if next_node_key not in current_node.children:
@@ -123,6 +174,220 @@ def add_route_to_trie(
     return current_node
 
 
+def remove_route_from_trie(
+    mount_routes: dict[str, RouteTrieNode],
+    plain_routes: set[str],
+    root_node: RouteTrieNode,
+    route: HTTPRoute | WebSocketRoute | ASGIRoute,
+    keys: Iterable[str] | None = None,
+) -> None:
+    """Remove the handlers of a route from the route_map tree.
+
+    Nodes left without handlers and without children are pruned, walking up the route's path components. The plain
+    and mount route entries of the route are dropped along with its node.
+
+    Args:
+        mount_routes: A dictionary mapping static routes to trie nodes.
+        plain_routes: A set of routes that do not have path parameters.
+        root_node: The root trie node.
+        route: The route that is being removed.
+        keys: The handler keys to remove, e.g. HTTP methods. Defaults to all keys of the route.
+
+    Returns:
+        None
+    """
+    node = get_route_node(root_node=root_node, mount_routes=mount_routes, route=route)
+    if node is None:
+        return
+
+    for key in get_route_handler_map(route) if keys is None else keys:
+        node.asgi_handlers.pop(key, None)
+        node.path_parameters.pop(key, None)
+        node.path_templates.pop(key, None)
+
+    if node.asgi_handlers:
+        return
+
+    node.is_asgi = node.is_mount = node.is_static = False
+    if mount_routes.get(route.path) is node:
+        del mount_routes[route.path]
+    if root_node.children.get(route.path) is node:
+        plain_routes.discard(route.path)
+        if not node.children and node is not root_node:
+            del root_node.children[route.path]
+            root_node.child_keys.discard(route.path)
+
+    parents: list[tuple[RouteTrieNode, str | PathSegmentPattern | type[PathParameterSentinel]]] = []
+    current_node = root_node
+    for component in route.path_components:
+        key = PathParameterSentinel if isinstance(component, PathParameterDefinition) else component
+        if key not in current_node.children:
+            return
+        parents.append((current_node, key))
+        current_node = current_node.children[key]
+
+    # plain routes are stored off the root node, their components may lead to the node of a different route
+    if current_node is not node:
+        return
+
+    for parent, key in reversed(parents):
+        child = parent.children[key]
+        if child.asgi_handlers or child.children:
+            break
+        del parent.children[key]
+        parent.child_keys.discard(key)
+        if key is PathParameterSentinel:
+            parent.is_path_param_node = False
+        elif isinstance(key, PathSegmentPattern):
+            parent.segment_patterns.remove(key)
+
+
+def graft_trie(
+    mount_routes: dict[str, RouteTrieNode],
+    plain_routes: set[str],
+    root_node: RouteTrieNode,
+    prefix: str,
+    other_mount_routes: dict[str, RouteTrieNode],
+    other_plain_routes: set[str],
+    other_root_node: RouteTrieNode,
+    on_conflict: Literal["error", "prefer_self", "prefer_other"] = "error",
+) -> None:
+    """Graft a copy of another route_map tree under a prefix.
+
+    The handlers of the other tree are reused as they are, only the path templates, plain routes and mount routes are
+    prefixed. Nodes of both trees sharing a path are merged.
+
+    Args:
+        mount_routes: A dictionary mapping static routes to trie nodes.
+        plain_routes: A set of routes that do not have path parameters.
+        root_node: The root trie node.
+        prefix: A normalized path without path parameters, under which the other tree is grafted.
+        other_mount_routes: The mount routes of the other tree.
+        other_plain_routes: The plain routes of the other tree.
+        other_root_node: The root node of the other tree.
+        on_conflict: What to do if a path and handler key is handled by both trees: raise an exception, keep the
+            handler of this tree or use the handler of the other tree.
+
+    Raises:
+        RouteConflictException: If a path and handler key is handled by both trees and ``on_conflict`` is
+            ``"error"``.
+
+    Returns:
+        None
+    """
+    prefix = "" if prefix == "/" else prefix
+    copies: dict[int, RouteTrieNode] = {}
+    # the nodes of this tree that copied nodes have been merged into
+    grafted: dict[int, RouteTrieNode] = {}
+
+    def prefixed(path: str) -> str:
+        return sys.intern(f"{prefix}{path}" if path != "/" else prefix or "/")
+
+    def copy_node(node: RouteTrieNode) -> RouteTrieNode:
+        # mount nodes can be reached both via their full path and via their path components
+        if id(node) in copies:
+            return copies[id(node)]
+        new_node = copies[id(node)] = create_node()
+        new_node.asgi_handlers = dict(node.asgi_handlers)
+        new_node.path_parameters = dict(node.path_parameters)
+        new_node.path_templates = {key: prefixed(template) for key, template in node.path_templates.items()}
+        new_node.is_asgi, new_node.is_mount, new_node.is_static = node.is_asgi, node.is_mount, node.is_static
+        new_node.is_path_param_node, new_node.is_path_type = node.is_path_param_node, node.is_path_type
+        new_node.segment_patterns = list(node.segment_patterns)
+        new_node.children = {key: copy_node(child) for key, child in node.children.items()}
+        new_node.child_keys = set(node.child_keys)
+        return new_node
+
+    def merge_node(target: RouteTrieNode, source: RouteTrieNode, overwrite: bool) -> None:
+        if grafted.get(id(source)) is target:
+            return
+        grafted[id(source)] = target
+        for key, handler_tuple in source.asgi_handlers.items():
+            if overwrite or key not in target.asgi_handlers:
+                target.asgi_handlers[key] = handler_tuple
+                target.path_parameters[key] = source.path_parameters[key]
+                target.path_templates[key] = source.path_templates[key]
+        target.is_asgi = target.is_asgi or source.is_asgi
+        target.is_mount = target.is_mount or source.is_mount
+        target.is_static = target.is_static or source.is_static
+        target.is_path_param_node = target.is_path_param_node or source.is_path_param_node
+        target.is_path_type = target.is_path_type or source.is_path_type
+        target.segment_patterns.extend(
+            pattern for pattern in source.segment_patterns if pattern not in target.segment_patterns
+        )
+        for key, child in source.children.items():
+            add_child(target, key, child, overwrite)
+
+    def add_child(
+        parent: RouteTrieNode,
+        key: str | PathSegmentPattern | type[PathParameterSentinel],
+        child: RouteTrieNode,
+        overwrite: bool,
+    ) -> None:
+        if key in parent.children:
+            merge_node(parent.children[key], child, overwrite)
+        else:
+            parent.children[key] = grafted.get(id(child), child)
+        parent.child_keys = set(parent.children.keys())
+
+    def check_conflicts(target: RouteTrieNode | None, source: RouteTrieNode) -> None:
+        if target is None:
+            return
+        for key, handler_tuple in source.asgi_handlers.items():
+            if key in target.asgi_handlers:
+                raise create_route_conflict_exception(
+                    f"Handler {handler_tuple.handler} for {key!r} on path {source.path_templates[key]!r} conflicts "
+                    f"with handler {target.asgi_handlers[key].handler} on path {target.path_templates[key]!r}.",
+                    path=source.path_templates[key],
+                    route_handler=handler_tuple.handler,
+                    conflicting_path=target.path_templates[key],
+                    conflicting_handler=target.asgi_handlers[key].handler,
+                )
+        for key, child in source.children.items():
+            check_conflicts(target.children.get(key), child)
+
+    other_root = copy_node(other_root_node)
+    # plain and mount routes are keyed by their full path off the root node
+    full_path_children = {
+        prefixed(key): other_root.children.pop(key)
+        for key in list(other_root.children)
+        if isinstance(key, str) and key.startswith("/")
+    }
+    other_root.child_keys = set(other_root.children.keys())
+
+    if on_conflict == "error":
+        # conflicts are checked upfront, so that this tree is left unchanged if grafting fails
+        for key, child in full_path_children.items():
+            check_conflicts(root_node.children.get(key), child)
+        existing_prefix_node: RouteTrieNode | None = root_node
+        for component in prefix.split("/")[1:]:
+            existing_prefix_node = existing_prefix_node.children.get(component) if existing_prefix_node else None
+        check_conflicts(existing_prefix_node, other_root)
+
+    prefer_other = on_conflict == "prefer_other"
+    for key, child in full_path_children.items():
+        add_child(root_node, key, child, prefer_other)
+
+    prefix_node = root_node
+    for component in map(sys.intern, prefix.split("/")[1:]):
+        if component not in prefix_node.children:
+            prefix_node.children[component] = create_node()
+            prefix_node.child_keys = set(prefix_node.children.keys())
+        prefix_node = prefix_node.children[component]
+    merge_node(prefix_node, other_root, prefer_other)
+
+    plain_routes.update(prefixed(path) for path in other_plain_routes)
+    for path, node in other_mount_routes.items():
+        mount_node = grafted.get(id(copies[id(node)]), copies[id(node)])
+        mount_path = prefixed(path)
+        mount_routes[mount_path] = mount_node
+        if mount_path != "/":
+            if (existing := root_node.children.get(mount_path)) is not None and existing is not mount_node:
+                merge_node(mount_node, existing, not prefer_other)
+            root_node.children[mount_path] = mount_node
+            root_node.child_keys = set(root_node.children.keys())
+
+
 def configure_node(
     app: Litestar,
     route: HTTPRoute | WebSocketRoute | ASGIRoute,
@@ -130,27 +395,106 @@ def configure_node(
 ) -> None:
     """Set required attributes and route handlers on route_map tree node.
 
+    Several routes can share a node if their paths only differ in the names or types of their path parameters, e.g.
+    ``GET /items/{id:int}`` and ``DELETE /items/{id:str}``. The path parameter definitions are stored per method, so
+    each method parses the parameters as declared by its own route.
+
     Args:
         app: The Litestar app instance.
         route: The route that is being added.
         node: The trie node being configured.
 
+    Raises:
+        RouteConflictException: If a method of the route is already handled by a different route sharing the node.
+
     Returns:
         None
     """
+    from litestar.middleware.cors import CORSPreflightMiddleware
     from litestar.routes import HTTPRoute, WebSocketRoute
 
+    for key, handler in get_route_handler_map(route).items():
+        # OPTIONS handlers are generated for every HTTP route, so they are expected to be replaced
+        if key != "OPTIONS" and (existing := node.asgi_handlers.get(key)) and existing.handler is not handler:
+            existing_path = node.path_templates.get(key, route.path)
+            if existing_path == route.path:
+                raise create_route_conflict_exception(
+                    f"Handler {handler} for {key!r} on path {route.path!r} conflicts with handler {existing.handler}, "
+                    f"which is already registered for {key!r} on the same path.",
+                    path=route.path,
+                    route_handler=handler,
+                    conflicting_path=existing_path,
+                    conflicting_handler=existing.handler,
+                )
+            raise create_route_conflict_exception(
+                f"Handler {handler} for {key!r} on path {route.path!r} conflicts with handler {existing.handler}, "
+                f"which handles {key!r} on path {existing_path!r}, differing only in its path parameters. Path "
+                "parameters may differ between methods, but each method of a path can only be handled once.",
+                path=route.path,
+                route_handler=handler,
+                conflicting_path=existing_path,
+                conflicting_handler=existing.handler,
+            )
+
     if not node.path_parameters:
         node.path_parameters = {}
 
     if isinstance(route, HTTPRoute):
+        stacks: dict[tuple[int, Method | None], ASGIApp] = {}
         for method, handler_mapping in route.route_handler_map.items():
             handler, _ = handler_mapping
-            node.asgi_handlers[method] = ASGIHandlerTuple(
-                asgi_app=build_route_middleware_stack(app=app, route=route, route_handler=handler),
-                handler=handler,
-            )
+            # handlers declaring a host or a version are dispatched to by dispatchers wrapping the default handler
+            default: ASGIHandlerTuple | None = None
+            if media_type_handlers := route.media_type_handler_map.get(method):
+                default = ASGIHandlerTuple(
+                    asgi_app=create_media_type_dispatcher(
+                        {
+                            media_type: ASGIHandlerTuple(
+                                asgi_app=build_cached_middleware_stack(app, route, alternative, method, stacks),
+                                handler=alternative,
+                            )
+                            for media_type, (alternative, _) in media_type_handlers.items()
+                        }
+                    ),
+                    handler=handler,
+                )
+            elif not (handler.opt.get("host") or handler.opt.get("version")):
+                default = ASGIHandlerTuple(
+                    asgi_app=build_cached_middleware_stack(app, route, handler, method, stacks),
+                    handler=handler,
+                )
+            if version_handlers := route.version_handler_map.get(method):
+                default = ASGIHandlerTuple(
+                    asgi_app=create_version_dispatcher(
+                        {
+                            version: ASGIHandlerTuple(
+                                asgi_app=build_cached_middleware_stack(app, route, version_handler, method, stacks),
+                                handler=version_handler,
+                            )
+                            for version, (version_handler, _) in version_handlers.items()
+                        },
+                        default=default,
+                    ),
+                    handler=handler,
+                )
+            if host_handlers := route.host_handler_map.get(method):
+                asgi_app = create_host_dispatcher(
+                    {
+                        host: ASGIHandlerTuple(
+                            asgi_app=build_cached_middleware_stack(app, route, host_handler, method, stacks),
+                            handler=host_handler,
+                        )
+                        for host, (host_handler, _) in host_handlers.items()
+                    },
+                    default=default,
+                )
+            else:
+                asgi_app = cast("ASGIHandlerTuple", default).asgi_app
+            if method == "OPTIONS" and (cors_config := get_route_cors_config(route)):
+                asgi_app = CORSPreflightMiddleware(app=asgi_app, config=cors_config, allowed_methods=route.methods)
+            node.asgi_handlers[method] = ASGIHandlerTuple(asgi_app=asgi_app, handler=handler)
             node.path_parameters[method] = route.path_parameters
+            node.path_templates[method] = route.path
 
     elif isinstance(route, WebSocketRoute):
         node.asgi_handlers["websocket"] = ASGIHandlerTuple(
@@ -158,6 +502,7 @@ def configure_node(
             handler=route.route_handler,
         )
         node.path_parameters["websocket"] = route.path_parameters
+        node.path_templates["websocket"] = route.path
 
     else:
         node.asgi_handlers["asgi"] = ASGIHandlerTuple(
@@ -165,9 +510,186 @@ def configure_node(
             handler=route.route_handler,
         )
         node.path_parameters["asgi"] = route.path_parameters
+        node.path_templates["asgi"] = route.path
         node.is_asgi = True
 
 
+def replace_node_handler(app: Litestar, route: HTTPRoute, node: RouteTrieNode, route_handler: HTTPRouteHandler) -> None:
+    """Store a route handler on a trie node for the methods it handles, replacing the handlers of those methods.
+
+    Only the middleware stack of the route handler is built, the handlers of the other methods of the node are left
+    as they are.
+
+    Args:
+        app: The Litestar app instance.
+        route: The route the route handler is part of.
+        node: The trie node of the route.
+        route_handler: The route handler.
+
+    Returns:
+        None
+    """
+    from litestar.middleware.cors import CORSPreflightMiddleware
+
+    stacks: dict[tuple[int, Method | None], ASGIApp] = {}
+    for method in route_handler.http_methods:
+        asgi_app = build_cached_middleware_stack(app, route, route_handler, method, stacks)
+        if method == "OPTIONS" and (cors_config := get_route_cors_config(route)):
+            asgi_app = CORSPreflightMiddleware(app=asgi_app, config=cors_config, allowed_methods=route.methods)
+        node.asgi_handlers[method] = ASGIHandlerTuple(asgi_app=asgi_app, handler=route_handler)
+        node.path_parameters[method] = route.path_parameters
+        node.path_templates[method] = route.path
+
+
+def get_route_cors_config(route: HTTPRoute) -> CORSConfig | None:
+    """Retrieve the CORS config of a route, set via the ``cors_config`` key of the ``opt`` of one of its handlers.
+
+    Args:
+        route: An HTTP route.
+
+    Returns:
+        The CORS config of the first handler of the route declaring one, if any.
+    """
+    return next(
+        (handler.opt["cors_config"] for handler in route.route_handlers if handler.opt.get("cors_config")),
+        None,
+    )
+
+
+def _add_cache_key_discriminator(scope: Scope, name: str, value: str) -> None:
+    # a cache key created by the router from the path template must tell apart the handlers a dispatcher selects from
+    from litestar.utils.scope.state import ScopeState
+
+    connection_state = ScopeState.from_scope(scope)
+    if isinstance(connection_state.cache_key, str):
+        connection_state.cache_key = f"{connection_state.cache_key} {urlencode({name: value})}"
+
+
+def create_media_type_dispatcher(handlers: dict[str, ASGIHandlerTuple]) -> ASGIApp:
+    """Create an ASGI app dispatching to one of several handlers of a method, according to the ``Accept`` header.
+
+    Args:
+        handlers: A mapping of media types to handler tuples. The first entry is used if none of the media types is
+            accepted.
+
+    Returns:
+        An ASGI app.
+    """
+    from litestar.datastructures.headers import Accept, Headers
+
+    media_types = list(handlers)
+    default = media_types[0]
+
+    async def media_type_dispatcher(scope: Scope, receive: Receive, send: Send) -> None:
+        accept = Headers.from_scope(scope).get("accept")
+        best_match = Accept(accept).best_match(media_types, default=default) if accept else default
+        media_type = best_match if best_match is not None and best_match in handlers else default
+        asgi_app, scope["route_handler"] = handlers[media_type]
+        _add_cache_key_discriminator(scope, "media_type", media_type)
+        await asgi_app(scope, receive, send)
+
+    return media_type_dispatcher
+
+
+def create_host_dispatcher(handlers: dict[str, ASGIHandlerTuple], default: ASGIHandlerTuple | None) -> ASGIApp:
+    """Create an ASGI app dispatching to one of several handlers of a method, according to the ``Host`` header.
+
+    Exact host names take precedence over wildcard patterns such as ``*.example.com``, which match any subdomain of
+    ``example.com``. Of several matching wildcard patterns, the longest one is used.
+
+    Args:
+        handlers: A mapping of lowercase host patterns to handler tuples.
+        default: The handler tuple used if no host pattern matches, if any.
+
+    Returns:
+        An ASGI app.
+    """
+    from litestar.datastructures.headers import Headers
+    from litestar.exceptions import NotFoundException
+
+    wildcards = sorted(
+        ((pattern[1:], handler) for pattern, handler in handlers.items() if pattern.startswith("*.")),
+        key=lambda item: len(item[0]),
+        reverse=True,
+    )
+
+    def match_host(host: str) -> ASGIHandlerTuple | None:
+        if handler := handlers.get(host):
+            return handler
+        return next((handler for suffix, handler in wildcards if host.endswith(suffix)), default)
+
+    async def host_dispatcher(scope: Scope, receive: Receive, send: Send) -> None:
+        host = Headers.from_scope(scope).get("host", "").lower()
+        # strip the port, minding IPv6 addresses such as '[::1]:8000'
+        if ":" in host and not host.endswith("]"):
+            host = host.rsplit(":", 1)[0]
+        if not (handler := match_host(host)):
+            raise NotFoundException()
+        asgi_app, scope["route_handler"] = handler
+        _add_cache_key_discriminator(scope, "host", handler.handler.opt.get("host", ""))
+        await asgi_app(scope, receive, send)
+
+    return host_dispatcher
+
+
+def create_version_dispatcher(handlers: dict[str, ASGIHandlerTuple], default: ASGIHandlerTuple | None) -> ASGIApp:
+    """Create an ASGI app dispatching to one of several handlers of a method, according to the requested API version.
+
+    The requested version is determined by the router, see :class:`VersioningConfig
+    <litestar.config.versioning.VersioningConfig>`.
+
+    Args:
+        handlers: A mapping of API versions to handler tuples.
+        default: The handler tuple used if no handler serves the requested version, if any.
+
+    Returns:
+        An ASGI app.
+    """
+    from litestar.exceptions import NotFoundException
+    from litestar.utils.empty import value_or_default
+    from litestar.utils.scope.state import ScopeState
+
+    async def version_dispatcher(scope: Scope, receive: Receive, send: Send) -> None:
+        version = value_or_default(ScopeState.from_scope(scope).api_version, None)
+        if not (handler := handlers.get(version or "", default)):
+            raise NotFoundException()
+        asgi_app, scope["route_handler"] = handler
+        _add_cache_key_discriminator(scope, "version", handler.handler.opt.get("version", ""))
+        await asgi_app(scope, receive, send)
+
+    return version_dispatcher
+
+
+def build_cached_middleware_stack(
+    app: Litestar,
+    route: HTTPRoute,
+    route_handler: HTTPRouteHandler,
+    method: Method,
+    stacks: dict[tuple[int, Method | None], ASGIApp],
+) -> ASGIApp:
+    """Construct the middleware stack of a handler serving a method of an HTTP route, reusing stacks already built.
+
+    A handler serving several methods, or selected for several methods by media type, host or API version, is served
+    by a single middleware stack, since the stack only depends on the route and the handler. Handlers declaring a
+    ``max_concurrency`` get a stack per method though, as their concurrency limit applies to each method separately.
+
+    Args:
+        app: The Litestar app instance.
+        route: The route that is being added.
+        route_handler: The route handler that is being wrapped.
+        method: The HTTP method the stack serves.
+        stacks: The stacks built for the route so far, keyed by the identity of their handler and, if the stack is
+            specific to a method, the method.
+
+    Returns:
+        An ASGIApp that is composed of a "stack" of middlewares.
+    """
+    key = (id(route_handler), method if route_handler.opt.get("max_concurrency") is not None else None)
+    if (stack := stacks.get(key)) is None:
+        stack = stacks[key] = build_route_middleware_stack(app=app, route=route, route_handler=route_handler)
+    return stack
+
+
 def build_route_middleware_stack(
     app: Litestar,
     route: HTTPRoute | WebSocketRoute | ASGIRoute,
@@ -185,7 +707,9 @@ def build_route_middleware_stack(
     """
     from litestar.middleware.allowed_hosts import AllowedHostsMiddleware
     from litestar.middleware.compression import CompressionMiddleware
+    from litestar.middleware.concurrency_limit import ConcurrencyLimitMiddleware
     from litestar.middleware.csrf import CSRFMiddleware
+    from litestar.middleware.deprecation import DeprecationMiddleware
     from litestar.middleware.response_cache import ResponseCacheMiddleware
     from litestar.routes import HTTPRoute
 
@@ -195,6 +719,18 @@ def build_route_middleware_stack(
         exception_handlers=route_handler.resolve_exception_handlers(),
     )
 
+    if (max_concurrency := route_handler.opt.get("max_concurrency")) is not None:
+        asgi_handler = ConcurrencyLimitMiddleware(
+            app=asgi_handler,
+            max_concurrency=max_concurrency,
+            queue_excess=route_handler.opt.get("queue_excess", False),
+        )
+
+    if getattr(route_handler, "deprecated", False):
+        asgi_handler = DeprecationMiddleware(
+            app=asgi_handler, sunset=route_handler.opt.get("sunset"), link=route_handler.opt.get("deprecation_link")
+        )
+
     if app.csrf_config:
         asgi_handler = CSRFMiddleware(app=asgi_handler, config=app.csrf_config)
 
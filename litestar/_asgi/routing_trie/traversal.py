@@ -3,17 +3,31 @@ from __future__ import annotations
 from functools import lru_cache
 from typing import TYPE_CHECKING, Any, Pattern
 
-from litestar._asgi.routing_trie.types import PathParameterSentinel
+from litestar._asgi.routing_trie.types import PathParameterSentinel, child_label
+from litestar._asgi.utils import strip_response_body
 from litestar.exceptions import MethodNotAllowedException, NotFoundException
+from litestar.types.internal_types import PathParameterDefinition
 from litestar.utils import normalize_path
 
-__all__ = ("parse_node_handlers", "parse_path_params", "parse_path_to_route", "traverse_route_map")
+__all__ = (
+    "explain_route_path",
+    "find_route_node",
+    "get_allowed_methods",
+    "get_handler_key",
+    "get_route_node",
+    "parse_node_handlers",
+    "parse_path_params",
+    "parse_path_to_route",
+    "resolve_node_handler",
+    "traverse_route_map",
+)
 
 
 if TYPE_CHECKING:
+    from litestar._asgi.routing_trie.frozen import FrozenTrieNode
     from litestar._asgi.routing_trie.types import ASGIHandlerTuple, RouteTrieNode
+    from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
     from litestar.types import ASGIApp, Method, RouteHandlerType
-    from litestar.types.internal_types import PathParameterDefinition
 
 
 def traverse_route_map(
@@ -22,6 +36,8 @@ def traverse_route_map(
 ) -> tuple[RouteTrieNode, list[str], str]:
     """Traverses the application route mapping and retrieves the correct node for the request url.
 
+    Literal path components take precedence over segment patterns, which take precedence over placeholders.
+
     Args:
         root_node: The root trie node.
         path: The request's path.
@@ -41,6 +57,11 @@ def traverse_route_map(
             current_node = current_node.children[component]
             continue
 
+        if current_node.segment_patterns and (segment_match := _match_segment_patterns(current_node, component)):
+            current_node, values = segment_match
+            path_params.extend(values)
+            continue
+
         if current_node.is_path_param_node:
             current_node = current_node.children[PathParameterSentinel]
 
@@ -59,12 +80,55 @@ def traverse_route_map(
     return current_node, path_params, path
 
 
+def _match_segment_patterns(node: RouteTrieNode, component: str) -> tuple[RouteTrieNode, tuple[str, ...]] | None:
+    for segment_pattern in node.segment_patterns:
+        if match := segment_pattern.pattern.fullmatch(component):
+            return node.children[segment_pattern], match.groups()
+    return None
+
+
+def get_route_node(
+    root_node: RouteTrieNode,
+    mount_routes: dict[str, RouteTrieNode],
+    route: HTTPRoute | WebSocketRoute | ASGIRoute,
+) -> RouteTrieNode | None:
+    """Retrieve the trie node a route has been inserted at.
+
+    Unlike :func:`traverse_route_map`, this follows the route's path template rather than a request path, so
+    parameter components are resolved to their placeholder or segment pattern node instead of being matched against
+    a value.
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping static routes to trie nodes.
+        route: The route to look up.
+
+    Returns:
+        The trie node of the route, or ``None`` if the route is not part of the trie.
+    """
+    if getattr(getattr(route, "route_handler", None), "is_mount", False):
+        return mount_routes.get(route.path)
+
+    if not route.path_parameters:
+        return root_node.children.get(route.path)
+
+    current_node = root_node
+    for component in route.path_components:
+        key = PathParameterSentinel if isinstance(component, PathParameterDefinition) else component
+        if key not in current_node.children:
+            return None
+        current_node = current_node.children[key]
+    return current_node
+
+
 def parse_node_handlers(
     node: RouteTrieNode,
     method: Method | None,
 ) -> ASGIHandlerTuple:
     """Retrieve the handler tuple from the node.
 
+    Falls back to the handler registered for the wildcard method ``"*"``, if any, for HTTP methods without a handler.
+
     Args:
         node: The trie node to parse.
         method: The scope's method.
@@ -76,11 +140,53 @@ def parse_node_handlers(
         An ASGI Handler tuple.
     """
 
+    return node.asgi_handlers[get_handler_key(node=node, method=method)]
+
+
+def get_handler_key(
+    node: RouteTrieNode | FrozenTrieNode, method: Method | None, head_falls_back_to_get: bool = False
+) -> str:
+    """Determine the key of the handler serving a method on a node.
+
+    Args:
+        node: The trie node.
+        method: The scope's method, if any.
+        head_falls_back_to_get: Whether ``HEAD`` requests are served by the ``GET`` handler if the node has no
+            ``HEAD`` handler.
+
+    Returns:
+        ``"asgi"`` for ASGI nodes, ``"websocket"`` if no method is given, the wildcard method ``"*"`` if the node has
+        no handler for the method but a wildcard handler, ``"GET"`` for ``HEAD`` requests falling back to the ``GET``
+        handler, or else the method itself.
+    """
     if node.is_asgi:
-        return node.asgi_handlers["asgi"]
-    if method:
-        return node.asgi_handlers[method]
-    return node.asgi_handlers["websocket"]
+        return "asgi"
+    if not method:
+        return "websocket"
+    if method in node.asgi_handlers:
+        return method
+    if "*" in node.asgi_handlers:
+        return "*"
+    if head_falls_back_to_get and method == "HEAD" and "GET" in node.asgi_handlers:
+        return "GET"
+    return method
+
+
+def get_allowed_methods(node: RouteTrieNode | FrozenTrieNode, head_falls_back_to_get: bool = False) -> set[str]:
+    """Determine the HTTP methods handled on a node.
+
+    Args:
+        node: The trie node.
+        head_falls_back_to_get: Whether ``HEAD`` requests are served by the ``GET`` handler if the node has no
+            ``HEAD`` handler.
+
+    Returns:
+        The HTTP methods of the node's handlers, excluding the websocket and ASGI handlers.
+    """
+    methods = {key for key in node.asgi_handlers if key not in {"asgi", "websocket"}}
+    if head_falls_back_to_get and "GET" in methods:
+        methods.add("HEAD")
+    return methods
 
 
 @lru_cache(1024)
@@ -94,15 +200,162 @@ def parse_path_params(
         path_param_values: The string values extracted from the url
 
     Raises:
-        ValueError: If any of path parameters can not be parsed into a value.
+        ValueError: If any of path parameters does not match its constraint or can not be parsed into a value.
 
     Returns:
         A dictionary of parsed path parameters.
     """
-    return {
-        param_definition.name: param_definition.parser(value) if param_definition.parser else value
-        for param_definition, value in zip(parameter_definitions, path_param_values)
-    }
+    path_params: dict[str, Any] = {}
+    for param_definition, value in zip(parameter_definitions, path_param_values):
+        if param_definition.pattern and not param_definition.pattern.fullmatch(value):
+            raise ValueError(f"Path parameter {param_definition.name} does not match its constraint")
+        path_params[param_definition.name] = param_definition.parser(value) if param_definition.parser else value
+    return path_params
+
+
+def find_route_node(
+    mount_paths_regex: Pattern | None,
+    mount_routes: dict[str, RouteTrieNode],
+    path: str,
+    plain_routes: set[str],
+    root_node: RouteTrieNode,
+) -> tuple[RouteTrieNode, list[str], str]:
+    """Find the trie node serving a request path, without selecting a handler or parsing path parameters.
+
+    Args:
+        mount_paths_regex: A compiled regex to match the mount routes.
+        mount_routes: Mapping of mount routes to trie nodes.
+        path: The request path.
+        plain_routes: The set of plain routes.
+        root_node: The root trie node.
+
+    Raises:
+        NotFoundException: If no correlating node is found.
+
+    Returns:
+        A tuple containing the trie node, the raw path parameter values and the path relative to the matched route.
+    """
+    if path in plain_routes:
+        return root_node.children[path], [], path
+
+    if mount_paths_regex and (match := mount_paths_regex.match(path)):
+        mount_path = match.group(0) or "/"
+        mount_node = mount_routes[mount_path]
+        remaining_path = path[match.end() :]
+        # since we allow regular handlers under static paths, we must validate that the request does not match
+        # any such handler.
+        next_component = remaining_path.split("/", 2)[1] if remaining_path else None
+        if next_component is None or next_component not in mount_node.children:
+            remaining_path = remaining_path or "/"
+            if not mount_node.is_static:
+                remaining_path = remaining_path if remaining_path.endswith("/") else f"{remaining_path}/"
+            return mount_node, [], remaining_path
+
+    return traverse_route_map(root_node=root_node, path=path)
+
+
+def _explain_step(component: str | None, decision: str, matched: bool, detail: str) -> dict[str, Any]:
+    return {"component": component, "decision": decision, "matched": matched, "detail": detail}
+
+
+def explain_route_path(
+    mount_paths_regex: Pattern | None,
+    mount_routes: dict[str, RouteTrieNode],
+    path: str,
+    plain_routes: set[str],
+    root_node: RouteTrieNode,
+) -> list[dict[str, Any]]:
+    """Describe the decisions made while routing a request path, following :func:`find_route_node`.
+
+    Args:
+        mount_paths_regex: A compiled regex to match the mount routes.
+        mount_routes: Mapping of mount routes to trie nodes.
+        path: The request path.
+        plain_routes: The set of plain routes.
+        root_node: The root trie node.
+
+    Returns:
+        A list of steps, each a dictionary holding the path ``component`` consumed by the step, if any, the
+        ``decision`` taken, i.e. ``"plain_route"``, ``"mount"``, ``"literal"``, ``"segment_pattern"``,
+        ``"placeholder"``, ``"no_match"`` or ``"handlers"``, whether the step ``matched`` and a human-readable
+        ``detail``. The path is not routed if the last step did not match.
+    """
+    if path in plain_routes:
+        node = root_node.children[path]
+        return [
+            _explain_step(None, "plain_route", True, f"{path!r} is a route without path parameters"),
+            _explain_step(None, "handlers", True, f"Handlers: {', '.join(sorted(node.asgi_handlers))}"),
+        ]
+
+    steps: list[dict[str, Any]] = []
+    if mount_paths_regex and (match := mount_paths_regex.match(path)):
+        mount_path = match.group(0) or "/"
+        remaining_path = path[match.end() :]
+        next_component = remaining_path.split("/", 2)[1] if remaining_path else None
+        if next_component is None or next_component not in mount_routes[mount_path].children:
+            return [_explain_step(None, "mount", True, f"Served by the app mounted at {mount_path!r}")]
+        steps.append(
+            _explain_step(
+                None,
+                "mount",
+                False,
+                f"Not served by the app mounted at {mount_path!r}, since a route is registered below it",
+            )
+        )
+
+    current_node = root_node
+    path_components = [p for p in path.split("/") if p]
+    for i, component in enumerate(path_components):
+        if component in current_node.child_keys:
+            current_node = current_node.children[component]
+            steps.append(_explain_step(component, "literal", True, f"Matched the literal component {component!r}"))
+            continue
+
+        if segment_pattern := next(
+            (key for key in current_node.segment_patterns if key.pattern.fullmatch(component)), None
+        ):
+            current_node = current_node.children[segment_pattern]
+            steps.append(
+                _explain_step(
+                    component, "segment_pattern", True, f"Matched the segment pattern {segment_pattern.template!r}"
+                )
+            )
+            continue
+
+        if current_node.is_path_param_node:
+            current_node = current_node.children[PathParameterSentinel]
+            if current_node.is_path_type:
+                remaining_path = normalize_path("/".join(path_components[i:]))
+                steps.append(
+                    _explain_step(
+                        component, "placeholder", True, f"Matched {remaining_path!r} with a 'path' type placeholder"
+                    )
+                )
+                break
+
+            steps.append(_explain_step(component, "placeholder", True, f"Matched {component!r} with a placeholder"))
+            continue
+
+        # the full paths of plain and mount routes are stored as children of the root node as well
+        labels = sorted(label for label in map(child_label, current_node.children) if not label.startswith("/"))
+        candidates = ", ".join(labels) or "none"
+        steps.append(
+            _explain_step(
+                component,
+                "no_match",
+                False,
+                f"No literal component, segment pattern or placeholder matches {component!r} (candidates: "
+                f"{candidates})",
+            )
+        )
+        return steps
+
+    if current_node.asgi_handlers:
+        handler_keys = ", ".join(sorted(current_node.asgi_handlers))
+        steps.append(_explain_step(None, "handlers", True, f"Handlers: {handler_keys}"))
+    else:
+        steps.append(_explain_step(None, "handlers", False, "No route handler is registered at this node"))
+    return steps
 
 
 def parse_path_to_route(
@@ -112,7 +365,8 @@ def parse_path_to_route(
     path: str,
     plain_routes: set[str],
     root_node: RouteTrieNode,
-) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any]]:
+    head_falls_back_to_get: bool = False,
+) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any], str, dict[str, str]]:
     """Given a scope object, retrieve the asgi_handlers and is_mount boolean values from correct trie node.
 
     Args:
@@ -122,47 +376,89 @@ def parse_path_to_route(
         plain_routes: The set of plain routes.
         mount_routes: Mapping of mount routes to trie nodes.
         mount_paths_regex: A compiled regex to match the mount routes.
+        head_falls_back_to_get: Whether ``HEAD`` requests are served by the ``GET`` handler if the node has no
+            ``HEAD`` handler. The response is then sent without a body.
 
     Raises:
         MethodNotAllowedException: if no matching method is found.
         NotFoundException: If no correlating node is found or if path params can not be parsed into values according to the node definition.
 
     Returns:
-        A tuple containing the stack of middlewares and the route handler that is wrapped by it.
+        A tuple containing the stack of middlewares and the route handler that is wrapped by it, the path relative to
+        the matched route, the parsed path parameters, the path template of the matched route and the raw path
+        parameters.
     """
 
     try:
-        if path in plain_routes:
-            asgi_app, handler = parse_node_handlers(node=root_node.children[path], method=method)
-            return asgi_app, handler, path, {}
-
-        if mount_paths_regex and (match := mount_paths_regex.search(path)):
-            mount_path = path[match.start() : match.end()]
-            mount_node = mount_routes[mount_path]
-            remaining_path = path[match.end() :]
-            # since we allow regular handlers under static paths, we must validate that the request does not match
-            # any such handler.
-            children = [sub_route for sub_route in mount_node.children or [] if sub_route != mount_path]
-            if not children or all(sub_route not in path for sub_route in children):  # type: ignore[operator]
-                asgi_app, handler = parse_node_handlers(node=mount_node, method=method)
-                remaining_path = remaining_path or "/"
-                if not mount_node.is_static:
-                    remaining_path = remaining_path if remaining_path.endswith("/") else f"{remaining_path}/"
-                return asgi_app, handler, remaining_path, {}
-
-        node, path_parameters, path = traverse_route_map(
-            root_node=root_node,
+        node, path_parameters, path = find_route_node(
+            mount_paths_regex=mount_paths_regex,
+            mount_routes=mount_routes,
             path=path,
+            plain_routes=plain_routes,
+            root_node=root_node,
+        )
+    except KeyError as e:
+        raise MethodNotAllowedException() from e
+    return resolve_node_handler(
+        node=node,
+        path_parameters=path_parameters,
+        path=path,
+        method=method,
+        head_falls_back_to_get=head_falls_back_to_get,
+    )
+
+
+def resolve_node_handler(
+    node: RouteTrieNode | FrozenTrieNode,
+    path_parameters: list[str],
+    path: str,
+    method: Method | None,
+    head_falls_back_to_get: bool = False,
+) -> tuple[ASGIApp, RouteHandlerType, str, dict[str, Any], str, dict[str, str]]:
+    """Select the handler of a trie node serving a method and parse the path parameter values of its route.
+
+    Args:
+        node: The trie node a request path has been routed to.
+        path_parameters: The raw path parameter values extracted from the request path.
+        path: The path relative to the matched route.
+        method: The scope's method, if any.
+        head_falls_back_to_get: Whether ``HEAD`` requests are served by the ``GET`` handler if the node has no
+            ``HEAD`` handler. The response is then sent without a body.
+
+    Raises:
+        MethodNotAllowedException: if no matching method is found.
+        NotFoundException: If path params can not be parsed into values according to the node definition.
+
+    Returns:
+        A tuple containing the stack of middlewares and the route handler that is wrapped by it, the path relative to
+        the matched route, the parsed path parameters, the path template of the matched route and the raw path
+        parameters.
+    """
+    try:
+        key = get_handler_key(node=node, method=method, head_falls_back_to_get=head_falls_back_to_get)
+        if key not in node.asgi_handlers:
+            raise MethodNotAllowedException(
+                allowed_methods=get_allowed_methods(node, head_falls_back_to_get=head_falls_back_to_get)
+            )
+        asgi_app, handler = node.asgi_handlers[key]
+        if key == "GET" and method == "HEAD":
+            asgi_app = strip_response_body(asgi_app)
+        parsed_path_parameters = (
+            parse_path_params(node.path_parameters[key], tuple(path_parameters)) if path_parameters else {}
+        )
+        raw_path_parameters = (
+            {definition.name: value for definition, value in zip(node.path_parameters[key], path_parameters)}
+            if path_parameters
+            else {}
         )
-        asgi_app, handler = parse_node_handlers(node=node, method=method)
-        key = method or ("asgi" if node.is_asgi else "websocket")
-        parsed_path_parameters = parse_path_params(node.path_parameters[key], tuple(path_parameters))
 
         return (
             asgi_app,
             handler,
             path,
             parsed_path_parameters,
+            node.path_templates[key],
+            raw_path_parameters,
         )
     except KeyError as e:
         raise MethodNotAllowedException() from e
@@ -1,14 +1,40 @@
 from __future__ import annotations
 
+import sys
 from dataclasses import dataclass
-from typing import TYPE_CHECKING, Literal, NamedTuple
+from typing import TYPE_CHECKING, Any, Literal, NamedTuple
 
-__all__ = ("ASGIHandlerTuple", "PathParameterSentinel", "RouteTrieNode", "create_node")
+__all__ = (
+    "ASGIHandlerTuple",
+    "MatchResult",
+    "PathParameterSentinel",
+    "RouteEntry",
+    "RouteMatch",
+    "RouteTrieNode",
+    "RoutingPath",
+    "TrieStats",
+    "UnreachableRoute",
+    "child_label",
+    "child_sort_key",
+    "collect_trie_routes",
+    "create_node",
+    "dump_trie_snapshot",
+    "get_trie_stats",
+    "render_trie_dot",
+    "render_trie_tree",
+    "serialize_trie",
+)
 
 
 if TYPE_CHECKING:
     from litestar.types import ASGIApp, Method, RouteHandlerType
-    from litestar.types.internal_types import PathParameterDefinition
+    from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
+
+
+# box drawing characters connecting the lines of a rendered tree
+_branch = "\u251c\u2500\u2500 "
+_last_branch = "\u2514\u2500\u2500 "
+_continuation = "\u2502   "
 
 
 class PathParameterSentinel:
@@ -24,6 +50,94 @@ class ASGIHandlerTuple(NamedTuple):
     """The route handler instance."""
 
 
+class RouteEntry(NamedTuple):
+    """A route handler stored on the routing trie, together with the path template and methods it serves."""
+
+    path: str
+    """The path template of the route, e.g. ``/users/{user_id:int}``."""
+    methods: tuple[str, ...]
+    """The sorted keys the handler is stored under, i.e. HTTP methods, ``"websocket"`` or ``"asgi"``."""
+    handler: RouteHandlerType
+    """The route handler instance."""
+
+
+class UnreachableRoute(NamedTuple):
+    """A route stored on the routing trie that no request can ever be routed to."""
+
+    path: str
+    """The path template of the route, e.g. ``/files/{name:str}.json``."""
+    methods: tuple[str, ...]
+    """The sorted keys the handler is stored under, i.e. HTTP methods, ``"websocket"`` or ``"asgi"``."""
+    reason: str
+    """A description of why the route cannot be matched."""
+
+
+class TrieStats(NamedTuple):
+    """Statistics of a routing trie, e.g. for capacity planning of large route tables."""
+
+    node_count: int
+    """The number of nodes of the trie, including the root node."""
+    max_depth: int
+    """The number of nodes on the longest path from the root node to a leaf node, excluding the root node."""
+    placeholder_count: int
+    """The number of nodes matching a path parameter spanning a whole path component."""
+    estimated_memory: int
+    """The estimated size of the nodes in bytes, excluding the route handlers and ASGI apps stored on them."""
+
+
+class RouteMatch(NamedTuple):
+    """The route a request path and method are routed to."""
+
+    path_template: str
+    """The path template of the matched route, e.g. ``/users/{user_id:int}``."""
+    path_params: dict[str, str]
+    """The raw, unparsed values of the path parameters, keyed by parameter name."""
+    handler_type: Literal["http", "websocket", "asgi"]
+    """The type of the matched route handler."""
+    handler: RouteHandlerType
+    """The matched route handler instance."""
+    opt: dict[str, Any]
+    """The ``opt`` of the matched route handler, holding metadata such as tags or the team owning the route."""
+
+
+class MatchResult(NamedTuple):
+    """The outcome of routing a connection, see
+    :meth:`ASGIRouter.resolve <litestar._asgi.asgi_router.ASGIRouter.resolve>`.
+    """
+
+    asgi_app: ASGIApp
+    """The ASGI app serving the connection."""
+    path_template: str | None
+    """The path template of the matched route, e.g. ``/users/{user_id:int}``, or ``None`` if no route has been matched,
+    i.e. if the connection is served by the fallback app or redirected to its canonical path.
+    """
+    raw_path_params: dict[str, str]
+    """The raw, unparsed values of the path parameters, keyed by parameter name."""
+    path_params: dict[str, Any]
+    """The parsed values of the path parameters, keyed by parameter name."""
+    handler_type: Literal["http", "websocket", "asgi"] | None
+    """The type of the matched route handler, or ``None`` if no route has been matched."""
+    handler: RouteHandlerType | None
+    """The matched route handler instance, or ``None`` if no route has been matched."""
+
+
+class RoutingPath(NamedTuple):
+    """The path a connection is routed by, see
+    :meth:`ASGIRouter.get_routing_path <litestar._asgi.asgi_router.ASGIRouter.get_routing_path>`.
+    """
+
+    path: str
+    """The normalized path, relative to the ``root_path`` of the connection and to the version prefix."""
+    keep_encoded_slashes: bool
+    """Whether encoded slashes within path segments are kept in the path as
+    :data:`ENCODED_SLASH_MARKER <litestar._asgi.asgi_router.ENCODED_SLASH_MARKER>`.
+    """
+    version: str | None
+    """The requested API version, or ``None`` if versioning is disabled or no version has been requested."""
+    version_prefix: str
+    """The version prefix stripped from the path, e.g. ``/v2``, or an empty string."""
+
+
 @dataclass(unsafe_hash=True)
 class RouteTrieNode:
     """A radix trie node."""
@@ -38,16 +152,18 @@ class RouteTrieNode:
         "is_path_param_node",
         "is_path_type",
         "path_parameters",
+        "path_templates",
+        "segment_patterns",
     )
 
     asgi_handlers: dict[Method | Literal["websocket", "asgi"], ASGIHandlerTuple]
     """A mapping of ASGI handlers stored on the node."""
-    child_keys: set[str | type[PathParameterSentinel]]
+    child_keys: set[str | PathSegmentPattern | type[PathParameterSentinel]]
     """
     A set containing the child keys, same as the children dictionary - but as a set, which offers faster lookup.
     """
-    children: dict[str | type[PathParameterSentinel], RouteTrieNode]
-    """A dictionary mapping path components or using the PathParameterSentinel class to child nodes."""
+    children: dict[str | PathSegmentPattern | type[PathParameterSentinel], RouteTrieNode]
+    """A dictionary mapping path components, segment patterns or the PathParameterSentinel class to child nodes."""
     is_path_param_node: bool
     """Designates the node as having a path parameter."""
     is_path_type: bool
@@ -63,6 +179,59 @@ class RouteTrieNode:
 
     This is used for parsing extracted path parameter values.
     """
+    path_templates: dict[Method | Literal["websocket"] | Literal["asgi"], str]
+    """A mapping of the keys of the handlers stored on the node to the path templates of their routes."""
+    segment_patterns: list[PathSegmentPattern]
+    """The keys of the children matching path components mixing literal text and placeholders, in the order in which
+    they are tried.
+    """
+
+    def __repr__(self) -> str:
+        flags = [
+            name
+            for name in ("is_asgi", "is_mount", "is_static", "is_path_param_node", "is_path_type")
+            if getattr(self, name)
+        ]
+        children = ", ".join(
+            f"{'{}' if key is PathParameterSentinel else repr(child_label(key))}: {self.children[key]!r}"
+            for key in sorted(self.children, key=child_sort_key)
+            if self.children[key] is not self
+        )
+        return (
+            f"{type(self).__name__}(asgi_handlers={sorted(self.asgi_handlers)!r}, flags={flags!r}, "
+            f"children={{{children}}})"
+        )
+
+
+def child_sort_key(key: str | PathSegmentPattern | type[PathParameterSentinel]) -> tuple[int, str]:
+    """Sort key for the children of a trie node, ordering literal components before segment patterns and the
+    placeholder.
+
+    Args:
+        key: A key of :attr:`RouteTrieNode.children`.
+
+    Returns:
+        A tuple usable as a sort key.
+    """
+    if key is PathParameterSentinel:
+        return 2, ""
+    if isinstance(key, str):
+        return 0, key
+    return 1, key.template  # type: ignore[union-attr]
+
+
+def child_label(key: str | PathSegmentPattern | type[PathParameterSentinel]) -> str:
+    """Describe a key of :attr:`RouteTrieNode.children`, e.g. for debugging output.
+
+    Args:
+        key: A key of :attr:`RouteTrieNode.children`.
+
+    Returns:
+        The path component, the template of the segment pattern, or ``"{}"`` for the placeholder.
+    """
+    if key is PathParameterSentinel:
+        return "{}"
+    return key if isinstance(key, str) else key.template  # type: ignore[union-attr]
 
 
 def create_node() -> RouteTrieNode:
@@ -82,4 +251,293 @@ def create_node() -> RouteTrieNode:
         is_static=False,
         is_path_type=False,
         path_parameters={},
+        path_templates={},
+        segment_patterns=[],
     )
+
+
+def collect_trie_routes(root_node: RouteTrieNode) -> list[RouteEntry]:
+    """Reconstruct the registered routes from a routing trie.
+
+    Handlers stored under several keys of a node for the same path template, e.g. a handler serving both ``GET`` and
+    ``HEAD``, are combined into a single entry.
+
+    Args:
+        root_node: The root trie node.
+
+    Returns:
+        A list of route entries, sorted by path template and methods.
+    """
+    entries: dict[tuple[str, int], list[str]] = {}
+    handlers: dict[int, RouteHandlerType] = {}
+    visited: set[int] = set()
+    stack = [root_node]
+    while stack:
+        node = stack.pop()
+        # mount nodes can be reached both via their full path and via their path components
+        if id(node) in visited:
+            continue
+        visited.add(id(node))
+        for key, (_, handler) in node.asgi_handlers.items():
+            handlers[id(handler)] = handler
+            entries.setdefault((node.path_templates[key], id(handler)), []).append(key)
+        stack.extend(node.children.values())
+
+    return sorted(
+        (
+            RouteEntry(path=path, methods=tuple(sorted(keys)), handler=handlers[handler_id])
+            for (path, handler_id), keys in entries.items()
+        ),
+        key=lambda entry: (entry.path, entry.methods),
+    )
+
+
+def get_trie_stats(root_node: RouteTrieNode) -> TrieStats:
+    """Compute statistics of a routing trie.
+
+    Args:
+        root_node: The root trie node.
+
+    Returns:
+        The statistics of the trie.
+    """
+    node_count = max_depth = placeholder_count = estimated_memory = 0
+    visited: set[int] = set()
+    stack: list[tuple[RouteTrieNode, int, bool]] = [(root_node, 0, False)]
+    while stack:
+        node, depth, is_placeholder = stack.pop()
+        max_depth = max(max_depth, depth)
+        # mount nodes can be reached both via their full path and via their path components, but are counted once
+        if id(node) not in visited:
+            visited.add(id(node))
+            node_count += 1
+            placeholder_count += is_placeholder
+            estimated_memory += sys.getsizeof(node) + sum(
+                sys.getsizeof(container)
+                for container in (
+                    node.asgi_handlers,
+                    node.child_keys,
+                    node.children,
+                    node.path_parameters,
+                    node.path_templates,
+                    node.segment_patterns,
+                )
+            )
+        stack.extend((child, depth + 1, key is PathParameterSentinel) for key, child in node.children.items())
+
+    return TrieStats(
+        node_count=node_count,
+        max_depth=max_depth,
+        placeholder_count=placeholder_count,
+        estimated_memory=estimated_memory,
+    )
+
+
+def _describe_node(node: RouteTrieNode) -> str:
+    sections = []
+    if node.asgi_handlers:
+        handlers = ", ".join(f"{key}={node.asgi_handlers[key].handler!s}" for key in sorted(node.asgi_handlers))
+        sections.append(f"handlers: {handlers}")
+    if flags := [
+        name
+        for name in ("is_asgi", "is_mount", "is_static", "is_path_param_node", "is_path_type")
+        if getattr(node, name)
+    ]:
+        sections.append(f"flags: {', '.join(flags)}")
+    if node.path_parameters:
+        parameters = ", ".join(
+            f"{key}=({', '.join(definition.full.replace(' ', '') for definition in node.path_parameters[key])})"
+            for key in sorted(node.path_parameters)
+        )
+        sections.append(f"params: {parameters}")
+    return "".join(f"  {section}" for section in sections)
+
+
+def dump_trie_snapshot(root_node: RouteTrieNode, mount_routes: dict[str, RouteTrieNode], plain_routes: set[str]) -> str:
+    """Render the state of a routing trie as a stable, sorted text dump.
+
+    This is meant for golden file tests, verifying that changes to the trie construction leave its structure intact.
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping mount routes to trie nodes.
+        plain_routes: The set of plain routes.
+
+    Returns:
+        The text dump, one node per line, with children indented below their parent.
+    """
+    lines = [f"plain routes: {', '.join(sorted(plain_routes))}", f"mount routes: {', '.join(sorted(mount_routes))}"]
+
+    def dump_node(label: str, node: RouteTrieNode, depth: int) -> None:
+        lines.append(f"{'  ' * depth}{label}{_describe_node(node)}")
+        for key in sorted(node.children, key=child_sort_key):
+            if node.children[key] is not node:
+                dump_node(child_label(key), node.children[key], depth + 1)
+
+    dump_node("<root>", root_node, 0)
+    return "\n".join(lines) + "\n"
+
+
+def _placeholder_label(node: RouteTrieNode, index: int) -> str:
+    # placeholders are shared by all routes below them, so the parameter is described by the first route found
+    stack = [node]
+    while stack:
+        current = stack.pop()
+        for definitions in current.path_parameters.values():
+            if len(definitions) > index:
+                return f"{{{definitions[index].full.replace(' ', '')}}}"
+        stack.extend(child for child in current.children.values() if child is not current)
+    return "{}"
+
+
+def _describe_handlers(node: RouteTrieNode) -> str:
+    if node.is_static:
+        return "[static mount]"
+    if node.is_mount:
+        return "[mount]"
+    if node.is_asgi:
+        return "[asgi]"
+    return ", ".join(sorted(node.asgi_handlers))
+
+
+def _labelled_children(
+    node: RouteTrieNode, mount_routes: dict[str, RouteTrieNode], is_root: bool, parameter_index: int
+) -> list[tuple[str, RouteTrieNode, int]]:
+    # mount nodes are stored below the root by their full path as well, but are only rendered by their components
+    children: list[tuple[str, RouteTrieNode, int]] = []
+    for key in sorted(node.children, key=child_sort_key):
+        child = node.children[key]
+        if child is node or (is_root and key in mount_routes):
+            continue
+        if key is PathParameterSentinel:
+            children.append((_placeholder_label(child, parameter_index), child, parameter_index + 1))
+        elif isinstance(key, str):
+            children.append((key, child, parameter_index))
+        else:
+            children.append((child_label(key), child, parameter_index + len(key.parameters)))  # type: ignore[union-attr]
+    return children
+
+
+def render_trie_tree(root_node: RouteTrieNode, mount_routes: dict[str, RouteTrieNode]) -> str:
+    """Render a routing trie as an indented tree, e.g. to inspect the routing of a large application.
+
+    Each line shows a literal path component, a segment pattern or a placeholder, followed by the keys of the handlers
+    stored on the node, i.e. HTTP methods or ``websocket``, or the kind of mount. Routes without path parameters are
+    stored by their full path below the root. Mount routes are shown by their path components only.
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping mount routes to trie nodes.
+
+    Returns:
+        The rendered tree.
+    """
+    lines = [f"/  {handlers}" if (handlers := _describe_handlers(root_node)) else "/"]
+
+    def render_children(node: RouteTrieNode, prefix: str, parameter_index: int) -> None:
+        children = _labelled_children(node, mount_routes, node is root_node, parameter_index)
+        for i, (label, child, child_parameter_index) in enumerate(children):
+            is_last = i == len(children) - 1
+            handlers = _describe_handlers(child)
+            lines.append(f"{prefix}{_last_branch if is_last else _branch}{label}{f'  {handlers}' if handlers else ''}")
+            render_children(child, prefix + ("    " if is_last else _continuation), child_parameter_index)
+
+    render_children(root_node, "", 0)
+    return "\n".join(lines) + "\n"
+
+
+def _dot_label(*lines: str) -> str:
+    escaped = (line.replace("\\", "\\\\").replace('"', '\\"') for line in lines if line)
+    return '"' + "\\n".join(escaped) + '"'
+
+
+def render_trie_dot(root_node: RouteTrieNode, mount_routes: dict[str, RouteTrieNode]) -> str:
+    """Render a routing trie as a Graphviz DOT graph, e.g. to visualize the routing of an application in documentation.
+
+    Each node is labelled with its path component, segment pattern or placeholder and the keys of the handlers stored on
+    it, laid out like :func:`render_trie_tree`.
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping mount routes to trie nodes.
+
+    Returns:
+        The DOT source of a directed graph.
+    """
+    lines = [
+        "digraph routes {",
+        "  node [shape=box];",
+        f"  n0 [label={_dot_label('/', _describe_handlers(root_node))}];",
+    ]
+    node_count = 1
+
+    def render_children(node: RouteTrieNode, node_id: int, parameter_index: int) -> None:
+        nonlocal node_count
+        for label, child, child_parameter_index in _labelled_children(
+            node, mount_routes, node is root_node, parameter_index
+        ):
+            child_id = node_count
+            node_count += 1
+            lines.append(f"  n{child_id} [label={_dot_label(label, _describe_handlers(child))}];")
+            lines.append(f"  n{node_id} -> n{child_id};")
+            render_children(child, child_id, child_parameter_index)
+
+    render_children(root_node, 0, 0)
+    lines.append("}")
+    return "\n".join(lines) + "\n"
+
+
+def serialize_trie(
+    root_node: RouteTrieNode, mount_routes: dict[str, RouteTrieNode], plain_routes: set[str]
+) -> dict[str, Any]:
+    """Describe the state of a routing trie with JSON compatible values, leaving out handlers and ASGI apps.
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping mount routes to trie nodes.
+        plain_routes: The set of plain routes.
+
+    Returns:
+        A dictionary of the routes stored on the trie, sorted by path template, together with their methods, the names
+        of their handlers and their path parameter definitions, the plain routes and the mount routes. Handlers without
+        a name are identified by the qualified name of their function.
+    """
+    routes: dict[str, dict[str, Any]] = {}
+    visited: set[int] = set()
+    stack = [root_node]
+    while stack:
+        node = stack.pop()
+        # mount nodes can be reached both via their full path and via their path components
+        if id(node) in visited:
+            continue
+        visited.add(id(node))
+        for key in node.asgi_handlers:
+            route = routes.setdefault(
+                node.path_templates[key],
+                {
+                    "path": node.path_templates[key],
+                    "methods": [],
+                    "handlers": {},
+                    "path_parameters": [
+                        {
+                            "name": definition.name,
+                            "type": definition.full.split(":", 2)[1].strip(),
+                            "pattern": definition.pattern.pattern if definition.pattern else None,
+                        }
+                        for definition in node.path_parameters.get(key, ())
+                    ],
+                },
+            )
+            route["methods"].append(key)
+            handler = node.asgi_handlers[key].handler
+            route["handlers"][key] = handler.name or str(handler)
+        stack.extend(node.children.values())
+
+    for route in routes.values():
+        route["methods"].sort()
+        route["handlers"] = dict(sorted(route["handlers"].items()))
+    return {
+        "routes": [routes[path] for path in sorted(routes)],
+        "plain_routes": sorted(plain_routes),
+        "mount_routes": [{"path": path, "is_static": mount_routes[path].is_static} for path in sorted(mount_routes)],
+    }
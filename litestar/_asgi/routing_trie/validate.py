@@ -1,15 +1,32 @@
 from __future__ import annotations
 
-from itertools import chain
-from typing import TYPE_CHECKING
+from pathlib import Path
+from typing import TYPE_CHECKING, Iterable
 
+from litestar._asgi.routing_trie.traversal import get_route_node
+from litestar._asgi.routing_trie.types import (
+    PathParameterSentinel,
+    UnreachableRoute,
+    child_label,
+    child_sort_key,
+    collect_trie_routes,
+)
+from litestar._asgi.utils import create_route_conflict_exception, get_route_handler_map, get_route_handlers
 from litestar.exceptions import ImproperlyConfiguredException
+from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
 
-__all__ = ("validate_node",)
+__all__ = (
+    "check_trie_invariants",
+    "find_unreachable_routes",
+    "validate_node",
+    "validate_placeholder_type",
+    "validate_route_path_parameters",
+)
 
 
 if TYPE_CHECKING:
     from litestar._asgi.routing_trie.types import RouteTrieNode
+    from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
 
 
 def validate_node(node: RouteTrieNode) -> None:
@@ -19,29 +36,352 @@ def validate_node(node: RouteTrieNode) -> None:
         node: A trie node.
 
     Raises:
-        ImproperlyConfiguredException
+        RouteConflictException: If an ASGI handler shares its path with other handlers, or if a route with path
+            parameters is registered under a static or mount route.
 
     Returns:
         None
     """
-    if node.is_asgi and bool(set(node.asgi_handlers).difference({"asgi"})):
-        raise ImproperlyConfiguredException("ASGI handlers must have a unique path not shared by other route handlers.")
+    if node.is_asgi and (other_keys := sorted(set(node.asgi_handlers).difference({"asgi"}))):
+        asgi_handler = node.asgi_handlers["asgi"].handler
+        others = ", ".join(f"{node.asgi_handlers[key].handler} ({key})" for key in other_keys)  # type: ignore[index]
+        raise create_route_conflict_exception(
+            "ASGI handlers must have a unique path not shared by other route handlers. ASGI handler "
+            f"{asgi_handler} shares its path with {others}.",
+            path=node.path_templates["asgi"],
+            route_handler=asgi_handler,
+            conflicting_path=node.path_templates[other_keys[0]],  # type: ignore[index]
+            conflicting_handler=node.asgi_handlers[other_keys[0]].handler,  # type: ignore[index]
+        )
 
-    if (
-        node.is_mount
-        and node.children
-        and any(
-            chain.from_iterable(
-                list(child.path_parameters.values())
-                if isinstance(child.path_parameters, dict)
-                else child.path_parameters
+    if node.is_mount and (
+        nested := next(
+            (
+                (child, key)
                 for child in node.children.values()
-            )
+                for key, parameters in child.path_parameters.items()
+                if parameters
+            ),
+            None,
         )
     ):
-        raise ImproperlyConfiguredException("Path parameters are not allowed under a static or mount route.")
+        child, key = nested
+        nested_handler = child.asgi_handlers[key].handler
+        mount_handler = node.asgi_handlers["asgi"].handler
+        raise create_route_conflict_exception(
+            f"Path parameters are not allowed under a static or mount route. Handler {nested_handler} on path "
+            f"{child.path_templates[key]!r} is nested under mount handler {mount_handler} on path "
+            f"{node.path_templates['asgi']!r}.",
+            path=child.path_templates[key],
+            route_handler=nested_handler,
+            conflicting_path=node.path_templates["asgi"],
+            conflicting_handler=mount_handler,
+        )
 
     for child in node.children.values():
         if child is node:
             continue
         validate_node(node=child)
+
+
+def validate_route_path_parameters(route: HTTPRoute | WebSocketRoute | ASGIRoute) -> None:
+    """Check that the path template of a route has been fully parsed into path parameter declarations.
+
+    Args:
+        route: The route that is being added.
+
+    Raises:
+        ImproperlyConfiguredException: If the path contains a placeholder that has not been parsed into a parameter
+            declaration, or if a parameter of type ``path`` is not the last component of the path.
+
+    Returns:
+        None
+    """
+    errors: list[str] = []
+
+    if unparsed := [c for c in route.path_components if isinstance(c, str) and ("{" in c or "}" in c)]:
+        errors.append(
+            f"components {', '.join(repr(c) for c in unparsed)} contain unbalanced braces or placeholders that are not "
+            "declared as '{param:type}'"
+        )
+
+    if catch_all := [
+        c.name for c in route.path_components[:-1] if isinstance(c, PathParameterDefinition) and c.type is Path
+    ]:
+        errors.append(
+            f"parameters {', '.join(catch_all)} of type 'path' consume the remainder of the path and must be its "
+            "last component"
+        )
+
+    if errors:
+        raise ImproperlyConfiguredException(
+            f"Path parameters of route '{route.path}' do not match its path template: {'; '.join(errors)}"
+        )
+
+
+def validate_placeholder_type(
+    node: RouteTrieNode,
+    parameter: PathParameterDefinition,
+    route: HTTPRoute | WebSocketRoute | ASGIRoute,
+) -> None:
+    """Validate that a path parameter can share its position in the trie with the placeholders of other routes.
+
+    Placeholders at the same position are stored on the same node regardless of their names and types, which is fine
+    as long as each route parses the value of the placeholder itself. A parameter of type ``path`` however consumes the
+    remainder of the request path, so sharing its position with a parameter of another type would silently make the
+    routes declaring the other parameter unreachable, e.g. ``/files/{name:path}`` and ``/files/{file_id:int}/meta``.
+
+    Args:
+        node: The node holding the placeholder child the parameter is added to.
+        parameter: The path parameter being added.
+        route: The route that is being added.
+
+    Raises:
+        RouteConflictException: If the parameter and the placeholders already stored at its position do not agree on
+            being of type ``path``.
+
+    Returns:
+        None
+    """
+    child = node.children.get(PathParameterSentinel)
+    if (
+        child is None
+        or (parameter.type is Path) == child.is_path_type
+        or not (existing_routes := collect_trie_routes(child))
+    ):
+        return
+
+    existing = existing_routes[0]
+    route_handler = get_route_handlers(route)[0]
+    raise create_route_conflict_exception(
+        f"Path parameter '{parameter.name}' of type '{parameter.full.split(':', 2)[1].strip()}' of route "
+        f"'{route.path}' conflicts with route '{existing.path}' ({', '.join(existing.methods)}), which declares a "
+        f"parameter of {'type' if child.is_path_type else 'a type other than'} 'path' at the same position. "
+        "Parameters of type 'path' consume the remainder of the path and cannot share their position with "
+        f"parameters of other types. Handler {route_handler} conflicts with handler {existing.handler}.",
+        path=route.path,
+        route_handler=route_handler,
+        conflicting_path=existing.path,
+        conflicting_handler=existing.handler,
+    )
+
+
+def check_trie_invariants(
+    root_node: RouteTrieNode,
+    mount_routes: dict[str, RouteTrieNode],
+    plain_routes: set[str],
+    routes: Iterable[HTTPRoute | WebSocketRoute | ASGIRoute],
+) -> list[str]:
+    """Check the structural invariants of a routing trie.
+
+    Unlike :func:`validate_node`, this does not raise on the first problem found but collects descriptions of all
+    violated invariants:
+
+    - Every handler of every route is reachable from the node of its route
+    - There are no nodes without handlers and without children
+    - The number of parameter definitions stored on a node matches the number of placeholders leading to it
+    - Plain routes are stored off the root node and do not also exist as a chain of components in the trie
+    - Every mount path is backed by an ASGI handler
+    - Routes registered below a mount path can be reached, i.e. are not swallowed by the mount
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping static routes to trie nodes.
+        plain_routes: The set of plain routes.
+        routes: The routes that have been added to the trie.
+
+    Returns:
+        A list of problem descriptions. Empty if all invariants hold.
+    """
+    problems: list[str] = []
+
+    for route in routes:
+        node = get_route_node(root_node=root_node, mount_routes=mount_routes, route=route)
+        if node is None:
+            problems.append(f"route {route.path!r} has no node in the trie")
+            continue
+        for key, handler in sorted(get_route_handler_map(route).items()):
+            if key not in node.asgi_handlers:
+                problems.append(f"handler {handler} for {key!r} on route {route.path!r} is not stored on its node")
+            elif key != "OPTIONS" and node.asgi_handlers[key].handler is not handler:
+                problems.append(
+                    f"handler {handler} for {key!r} on route {route.path!r} is shadowed by "
+                    f"{node.asgi_handlers[key].handler}"
+                )
+
+    for plain_route in sorted(plain_routes):
+        if plain_route not in root_node.children:
+            problems.append(f"plain route {plain_route!r} is not stored on the root node")
+            continue
+        current_node = root_node
+        for component in (c for c in plain_route.split("/") if c):
+            if component not in current_node.children:
+                break
+            current_node = current_node.children[component]
+        else:
+            if current_node is not root_node and current_node.asgi_handlers:
+                problems.append(f"plain route {plain_route!r} also exists as a chain of components in the trie")
+
+    for mount_path, mount_node in sorted(mount_routes.items()):
+        if not mount_node.is_mount or "asgi" not in mount_node.asgi_handlers:
+            problems.append(f"mount path {mount_path!r} is not backed by an ASGI handler")
+
+    for route in routes:
+        if mount_path := _find_swallowing_mount_path(root_node=root_node, mount_routes=mount_routes, route=route):
+            problems.append(f"route {route.path!r} is registered under mount path {mount_path!r} and cannot be reached")
+
+    visited: set[int] = {id(root_node)}
+    stack: list[tuple[RouteTrieNode, str, int]] = []
+    for key in sorted(root_node.children, key=child_sort_key, reverse=True):
+        # plain and mount routes are stored off the root node keyed by their full path
+        label = child_label(key)
+        path = label if label.startswith("/") else f"/{label}"
+        stack.append((root_node.children[key], path, _count_placeholders(key)))
+
+    while stack:
+        node, path, depth = stack.pop()
+        if id(node) in visited:
+            continue
+        visited.add(id(node))
+
+        if not node.asgi_handlers and not node.children:
+            problems.append(f"node {path!r} has neither handlers nor children")
+
+        for key, parameters in sorted(node.path_parameters.items()):
+            if len(parameters) != depth:
+                problems.append(
+                    f"node {path!r} stores {len(parameters)} path parameters for {key!r}, but is nested under "
+                    f"{depth} placeholders"
+                )
+
+        # children are pushed in reverse so that they are popped, and reported on, in sorted order
+        for key in sorted(node.children, key=child_sort_key, reverse=True):
+            child = node.children[key]
+            if child is node:
+                continue
+            stack.append((child, f"{path.rstrip('/')}/{child_label(key)}", depth + _count_placeholders(key)))
+
+    return problems
+
+
+def find_unreachable_routes(
+    root_node: RouteTrieNode,
+    mount_routes: dict[str, RouteTrieNode],
+    routes: Iterable[HTTPRoute | WebSocketRoute | ASGIRoute],
+    version_prefixes: Iterable[str] = (),
+) -> list[UnreachableRoute]:
+    """Find the routes stored on a routing trie that no request can ever be routed to.
+
+    A route cannot be matched if:
+
+    - It is registered below a mount path, but requests for it are passed to the mounted app instead
+    - One of its components is a segment pattern identical to a segment pattern registered before it, e.g.
+      ``{name:str}.json`` and ``{name:int}.json``, since the first matching pattern of a node is always used
+    - Its first component is an API version, which is stripped from request paths before they are routed if path
+      prefix versioning is enabled
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping static routes to trie nodes.
+        routes: The routes that have been added to the trie.
+        version_prefixes: The API versions stripped from request paths before routing.
+
+    Returns:
+        A list of unreachable routes, sorted by path template.
+    """
+    # keyed by path template, since the handlers of a route, e.g. its generated ``OPTIONS`` handler, share its fate
+    unreachable: dict[str, tuple[set[str], str]] = {}
+
+    for route in routes:
+        if mount_path := _find_swallowing_mount_path(root_node=root_node, mount_routes=mount_routes, route=route):
+            unreachable[route.path] = (
+                set(get_route_handler_map(route)),
+                f"requests are passed to the app mounted at {mount_path!r}",
+            )
+
+    versions = set(version_prefixes)
+    for key, node in root_node.children.items():
+        if not isinstance(key, str):
+            continue
+        # plain and mount routes are stored off the root node keyed by their full path
+        first_component = key.split("/", 2)[1] if key.startswith("/") else key
+        if first_component in versions:
+            reason = f"the API version {first_component!r} is stripped from request paths before routing"
+            for entry in collect_trie_routes(node):
+                unreachable.setdefault(entry.path, (set(), reason))[0].update(entry.methods)
+
+    visited: set[int] = set()
+    stack = [root_node]
+    while stack:
+        node = stack.pop()
+        if id(node) in visited:
+            continue
+        visited.add(id(node))
+        seen_patterns: dict[str, PathSegmentPattern] = {}
+        for segment_pattern in node.segment_patterns:
+            if shadowing_pattern := seen_patterns.get(segment_pattern.pattern.pattern):
+                reason = f"segment {segment_pattern.template!r} is pre-empted by segment {shadowing_pattern.template!r}"
+                for entry in collect_trie_routes(node.children[segment_pattern]):
+                    unreachable.setdefault(entry.path, (set(), reason))[0].update(entry.methods)
+            else:
+                seen_patterns[segment_pattern.pattern.pattern] = segment_pattern
+        stack.extend(child for child in node.children.values() if child is not node)
+
+    return [
+        UnreachableRoute(path=path, methods=tuple(sorted(methods)), reason=reason)
+        for path, (methods, reason) in sorted(unreachable.items())
+    ]
+
+
+def _find_swallowing_mount_path(
+    root_node: RouteTrieNode,
+    mount_routes: dict[str, RouteTrieNode],
+    route: HTTPRoute | WebSocketRoute | ASGIRoute,
+) -> str | None:
+    """Find the mount path a route is registered under if requests for the route are passed to the mounted app.
+
+    Args:
+        root_node: The root trie node.
+        mount_routes: A dictionary mapping static routes to trie nodes.
+        route: A route that has been added to the trie.
+
+    Returns:
+        The mount path, or ``None`` if the route can be reached.
+    """
+    if getattr(getattr(route, "route_handler", None), "is_mount", False):
+        return None
+    mount_path = _find_mount_path(route.path, mount_routes)
+    if not mount_path:
+        return None
+    if not route.path_parameters:
+        node = root_node.children.get(route.path)
+        is_reachable = node is not None and not node.is_mount
+    else:
+        remaining_path = route.path[len(mount_path.rstrip("/")) :]
+        next_component = remaining_path.split("/", 2)[1] if remaining_path else None
+        is_reachable = next_component is not None and next_component in mount_routes[mount_path].children
+    return None if is_reachable else mount_path
+
+
+def _count_placeholders(key: str | PathSegmentPattern | type[PathParameterSentinel]) -> int:
+    if key is PathParameterSentinel:
+        return 1
+    return len(key.parameters) if isinstance(key, PathSegmentPattern) else 0
+
+
+def _find_mount_path(path: str, mount_routes: dict[str, RouteTrieNode]) -> str | None:
+    """Find the most specific mount path a path is located under.
+
+    Args:
+        path: A route path.
+        mount_routes: A dictionary mapping static routes to trie nodes.
+
+    Returns:
+        The mount path, if any.
+    """
+    candidates = [
+        mount_path
+        for mount_path in mount_routes
+        if mount_path == "/" or path == mount_path or path.startswith(f"{mount_path}/")
+    ]
+    return max(candidates, key=len) if candidates else None
@@ -0,0 +1,122 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Callable, Mapping
+
+from litestar.exceptions import MethodNotAllowedException, NotFoundException
+
+__all__ = ("TenantASGIRouter",)
+
+
+if TYPE_CHECKING:
+    from litestar.app import Litestar
+    from litestar.types import LifeSpanReceive, LifeSpanScope, LifeSpanSend, Receive, Scope, Send
+
+
+class TenantASGIRouter:
+    """Route connections to per-tenant apps, falling back to a shared base app.
+
+    Each tenant app acts as an overlay of the base app: a connection is served by the tenant app selected by the
+    connection's tenant key if one of its routes matches, and by the base app otherwise.
+    """
+
+    __slots__ = ("base", "get_tenant_key", "tenants")
+
+    def __init__(
+        self,
+        base: Litestar,
+        tenants: Mapping[str, Litestar],
+        get_tenant_key: Callable[[Scope], str | None],
+    ) -> None:
+        """Initialize ``TenantASGIRouter``.
+
+        Args:
+            base: The app serving routes shared by all tenants. Lifespan events are sent to this app.
+            tenants: A mapping of tenant keys to apps serving tenant specific routes.
+            get_tenant_key: A callable extracting the tenant key from a connection scope.
+        """
+        self.base = base
+        self.tenants = dict(tenants)
+        self.get_tenant_key = get_tenant_key
+
+    @classmethod
+    def from_header(cls, base: Litestar, tenants: Mapping[str, Litestar], header_name: str) -> TenantASGIRouter:
+        """Create a ``TenantASGIRouter`` selecting the tenant by the value of a request header.
+
+        Args:
+            base: The app serving routes shared by all tenants.
+            tenants: A mapping of header values to apps serving tenant specific routes.
+            header_name: The name of the header holding the tenant key.
+
+        Returns:
+            A ``TenantASGIRouter``.
+        """
+        encoded_header_name = header_name.lower().encode("latin-1")
+
+        def get_tenant_key(scope: Scope) -> str | None:
+            return next(
+                (value.decode("latin-1") for name, value in scope.get("headers", []) if name == encoded_header_name),
+                None,
+            )
+
+        return cls(base=base, tenants=tenants, get_tenant_key=get_tenant_key)
+
+    @classmethod
+    def from_host(cls, base: Litestar, tenants: Mapping[str, Litestar]) -> TenantASGIRouter:
+        """Create a ``TenantASGIRouter`` selecting the tenant by the host of a request, without the port.
+
+        Args:
+            base: The app serving routes shared by all tenants.
+            tenants: A mapping of lowercase host names to apps serving tenant specific routes.
+
+        Returns:
+            A ``TenantASGIRouter``.
+        """
+        get_host = cls.from_header(base=base, tenants=tenants, header_name="host").get_tenant_key
+
+        def get_tenant_key(scope: Scope) -> str | None:
+            host = get_host(scope)
+            return host.rsplit(":", 1)[0].lower() if host and not host.endswith("]") else host
+
+        return cls(base=base, tenants=tenants, get_tenant_key=get_tenant_key)
+
+    def resolve_app(self, scope: Scope) -> Litestar:
+        """Resolve the app serving a connection.
+
+        Args:
+            scope: The ASGI connection scope.
+
+        Returns:
+            The tenant app, if it has a route matching the connection, otherwise the base app.
+        """
+        tenant_key = self.get_tenant_key(scope)
+        tenant = self.tenants.get(tenant_key) if tenant_key is not None else None
+        if tenant is None:
+            return self.base
+
+        router = tenant.asgi_router
+        try:
+            router.handle_routing(path=router.get_routing_path(scope).path, method=scope.get("method"))
+        except (MethodNotAllowedException, NotFoundException):
+            return self.base
+        return tenant
+
+    async def __call__(
+        self,
+        scope: Scope | LifeSpanScope,
+        receive: Receive | LifeSpanReceive,
+        send: Send | LifeSpanSend,
+    ) -> None:
+        """ASGI callable.
+
+        Args:
+            scope: The ASGI connection scope.
+            receive: The ASGI receive function.
+            send: The ASGI send function.
+
+        Returns:
+            None
+        """
+        if scope["type"] == "lifespan":
+            await self.base(scope, receive, send)
+            return
+        await self.resolve_app(scope)(scope, receive, send)  # type: ignore[arg-type]
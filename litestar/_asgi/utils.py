@@ -1,14 +1,110 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, cast
+import inspect
+from datetime import date, datetime, time, timedelta
+from decimal import Decimal
+from typing import TYPE_CHECKING, Any, Literal, NamedTuple, cast
+from urllib.parse import unquote_to_bytes
+from uuid import UUID
 
-__all__ = ("get_route_handlers", "wrap_in_exception_handler")
+from litestar.exceptions import ImproperlyConfiguredException, RouteConflictException
+from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
+
+__all__ = (
+    "PathParameterInfo",
+    "RouteDiff",
+    "RouteTableRow",
+    "create_route_conflict_exception",
+    "get_handler_location",
+    "get_handler_type",
+    "get_route_table_rows",
+    "get_path_parameter_info",
+    "get_route_handler_map",
+    "get_route_handlers",
+    "get_route_signature",
+    "get_scope_path",
+    "get_synthetic_path",
+    "strip_response_body",
+    "strip_root_path",
+    "warm_up_route_handler",
+    "wrap_in_exception_handler",
+)
 
 
 if TYPE_CHECKING:
     from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
     from litestar.routes.base import BaseRoute
-    from litestar.types import ASGIApp, ExceptionHandlersMap, RouteHandlerType
+    from litestar.types import ASGIApp, ExceptionHandlersMap, Message, Method, Receive, RouteHandlerType, Scope, Send
+
+
+_synthetic_path_parameter_values: dict[type, str] = {
+    int: "0",
+    float: "0",
+    Decimal: "0",
+    UUID: "00000000-0000-0000-0000-000000000000",
+    date: "2000-01-01",
+    datetime: "2000-01-01T00:00:00",
+    time: "00:00:00",
+    timedelta: "0",
+}
+_route_handler_resolvers = (
+    "resolve_dependencies",
+    "resolve_exception_handlers",
+    "resolve_guards",
+    "resolve_layered_parameters",
+    "resolve_middleware",
+    "resolve_signature_namespace",
+    "resolve_type_decoders",
+    "resolve_type_encoders",
+    "resolve_receive_handler",
+    "resolve_send_handler",
+    "get_response_handler",
+)
+_constraint_fields = ("gt", "ge", "lt", "le", "multiple_of", "min_length", "max_length", "pattern")
+
+
+class PathParameterInfo(NamedTuple):
+    """Structured description of a path parameter of a route."""
+
+    name: str
+    """The name of the parameter."""
+    type: type
+    """The type the parameter is declared with in the path."""
+    required: bool
+    """Whether the parameter is required."""
+    constraints: dict[str, Any]
+    """Constraints declared for the parameter via :func:`Parameter <litestar.params.Parameter>`, e.g. ``ge``."""
+
+
+class RouteTableRow(NamedTuple):
+    """A row of the route table, describing a single route handler of a route."""
+
+    path: str
+    """The path template of the route."""
+    scope_type: str
+    """The scope type the route serves, i.e. ``"http"``, ``"websocket"`` or ``"asgi"``."""
+    methods: tuple[str, ...]
+    """The sorted HTTP methods served by the handler. Empty for websocket and ASGI handlers."""
+    name: str | None
+    """The name of the handler, if any."""
+    handler: str
+    """The qualified name of the handler function."""
+    middleware_count: int
+    """The number of middlewares applied to the handler."""
+
+
+class RouteDiff(NamedTuple):
+    """The differences between the routing tables of two routers.
+
+    Routes are described by their signature, see :func:`get_route_signature`.
+    """
+
+    added: list[str]
+    """Routes only present in the other router."""
+    removed: list[str]
+    """Routes only present in this router."""
+    changed: list[tuple[str, str]]
+    """Pairs of routes present in both routers under the same path, but with different methods or parameters."""
 
 
 def wrap_in_exception_handler(app: ASGIApp, exception_handlers: ExceptionHandlersMap) -> ASGIApp:
@@ -26,6 +122,31 @@ def wrap_in_exception_handler(app: ASGIApp, exception_handlers: ExceptionHandler
     return ExceptionHandlerMiddleware(app=app, exception_handlers=exception_handlers, debug=None)
 
 
+def strip_response_body(app: ASGIApp) -> ASGIApp:
+    """Wrap the given ASGIApp so that its response is sent without a body, e.g. to answer a ``HEAD`` request.
+
+    The headers of the response, including its ``content-length``, are sent unchanged.
+
+    Args:
+        app: The ASGI app that is being wrapped.
+
+    Returns:
+        A wrapped ASGIApp.
+    """
+
+    async def wrapped_app(scope: Scope, receive: Receive, send: Send) -> None:
+        async def send_without_body(message: Message) -> None:
+            if message["type"] == "http.response.body":
+                if message.get("more_body", False):
+                    return
+                message = {"type": "http.response.body", "body": b"", "more_body": False}
+            await send(message)
+
+        await app(scope, receive, send_without_body)
+
+    return wrapped_app
+
+
 def get_route_handlers(route: BaseRoute) -> list[RouteHandlerType]:
     """Retrieve handler(s) as a list for given route.
 
@@ -42,3 +163,260 @@ def get_route_handlers(route: BaseRoute) -> list[RouteHandlerType]:
         route_handlers.append(cast("WebSocketRoute | ASGIRoute", route).route_handler)
 
     return route_handlers
+
+
+def get_route_handler_map(
+    route: HTTPRoute | WebSocketRoute | ASGIRoute,
+) -> dict[Method | Literal["websocket", "asgi"], RouteHandlerType]:
+    """Retrieve a mapping of the keys a route's handlers are stored under on a trie node to the handlers.
+
+    Args:
+        route: The route from which the route handlers are extracted.
+
+    Returns:
+        A dictionary mapping HTTP methods, ``"websocket"`` or ``"asgi"`` to route handlers.
+    """
+    from litestar.routes import HTTPRoute, WebSocketRoute
+
+    if isinstance(route, HTTPRoute):
+        return {method: handler for method, (handler, _) in route.route_handler_map.items()}
+    if isinstance(route, WebSocketRoute):
+        return {"websocket": route.route_handler}
+    return {"asgi": route.route_handler}
+
+
+def get_handler_type(
+    route_handler: RouteHandlerType, scope_type: Literal["http", "websocket"]
+) -> Literal["http", "websocket", "asgi"]:
+    """Determine the type of the route handler a connection has been routed to.
+
+    Args:
+        route_handler: The route handler.
+        scope_type: The type of the connection scope.
+
+    Returns:
+        ``"asgi"`` for ASGI route handlers, otherwise the type of the connection scope.
+    """
+    from litestar.handlers.asgi_handlers import ASGIRouteHandler
+
+    return "asgi" if isinstance(route_handler, ASGIRouteHandler) else scope_type
+
+
+def get_handler_location(route_handler: RouteHandlerType) -> str | None:
+    """Determine the source location of the function of a route handler.
+
+    Args:
+        route_handler: The route handler.
+
+    Returns:
+        The location as ``file:line``, or ``None`` if it cannot be determined, e.g. for builtins.
+    """
+    from litestar.utils.helpers import unwrap_partial
+
+    target: Any = unwrap_partial(route_handler.fn)
+    if not hasattr(target, "__qualname__"):
+        target = type(target)
+    try:
+        source_file = inspect.getsourcefile(target)
+        _, line = inspect.getsourcelines(target)
+    except (OSError, TypeError):
+        return None
+    return f"{source_file}:{line}" if source_file else None
+
+
+def create_route_conflict_exception(
+    message: str,
+    path: str,
+    route_handler: RouteHandlerType,
+    conflicting_path: str,
+    conflicting_handler: RouteHandlerType,
+) -> RouteConflictException:
+    """Create an exception reporting a conflict between two route handlers.
+
+    The source locations of both handlers are appended to the message, so that the handlers can be found even if
+    they share their names, e.g. handlers of different controllers.
+
+    Args:
+        message: The description of the conflict.
+        path: The path template of the route of the handler.
+        route_handler: The handler.
+        conflicting_path: The path template of the route of the conflicting handler.
+        conflicting_handler: The conflicting handler.
+
+    Returns:
+        The exception.
+    """
+    handler_location = get_handler_location(route_handler)
+    conflicting_handler_location = get_handler_location(conflicting_handler)
+    if locations := [
+        f"{handler} ({location})"
+        for handler, location in (
+            (route_handler, handler_location),
+            (conflicting_handler, conflicting_handler_location),
+        )
+        if location
+    ]:
+        message = f"{message} Handler locations: {', '.join(locations)}."
+    return RouteConflictException(
+        message,
+        path=path,
+        handler_name=str(route_handler),
+        handler_location=handler_location,
+        conflicting_path=conflicting_path,
+        conflicting_handler_name=str(conflicting_handler),
+        conflicting_handler_location=conflicting_handler_location,
+    )
+
+
+def get_scope_path(scope: Scope) -> str:
+    """Retrieve the decoded path of a connection from the scope.
+
+    Falls back to decoding ``raw_path`` for scopes that do not contain a ``path``, which some middlewares and test
+    utilities construct.
+
+    Args:
+        scope: The ASGI connection scope.
+
+    Raises:
+        ImproperlyConfiguredException: If the scope contains neither ``path`` nor ``raw_path``.
+
+    Returns:
+        The decoded path.
+    """
+    if (path := scope.get("path")) is not None:
+        return path
+    if (raw_path := scope.get("raw_path")) is not None:
+        # some servers include the query string in raw_path
+        return unquote_to_bytes(raw_path.split(b"?", 1)[0]).decode("utf-8", errors="replace")
+    raise ImproperlyConfiguredException("The ASGI scope must contain either a 'path' or a 'raw_path'")
+
+
+def strip_root_path(path: str, root_path: str) -> str:
+    """Strip the ``root_path`` of a connection from its path.
+
+    Per the ASGI spec, the ``path`` of a connection includes its ``root_path``. The ``root_path`` is only stripped if it
+    is a prefix of the path ending at a segment boundary, so that e.g. ``/application`` is not stripped to ``lication``
+    by a ``root_path`` of ``/app``, and a ``root_path`` occurring later in the path, e.g. ``/other/app``, is never
+    stripped. Paths not starting with the ``root_path`` are considered relative to it already, as sent by servers
+    predating the spec clarification and by applications mounting other ASGI apps.
+
+    Args:
+        path: The path of the connection.
+        root_path: The ``root_path`` of the connection.
+
+    Returns:
+        The path relative to the ``root_path``.
+    """
+    root_path = root_path.rstrip("/")
+    if root_path and path.startswith(root_path) and path[len(root_path) : len(root_path) + 1] in ("", "/"):
+        return path[len(root_path) :]
+    return path
+
+
+def get_path_parameter_info(route: BaseRoute) -> tuple[PathParameterInfo, ...]:
+    """Retrieve structured information about the path parameters of a route.
+
+    Constraints are collected from the signatures of the route's handlers. Path parameters are always required.
+
+    Args:
+        route: The route from which the path parameters are extracted.
+
+    Returns:
+        The path parameters of the route, in the order they appear in the path.
+    """
+    from litestar.params import KwargDefinition
+
+    route_handlers = get_route_handlers(route)
+    path_parameter_info: list[PathParameterInfo] = []
+    for parameter in route.path_parameters:
+        constraints: dict[str, Any] = {}
+        for route_handler in route_handlers:
+            field_definition = route_handler.parsed_fn_signature.parameters.get(parameter.name)
+            if field_definition is not None and isinstance(field_definition.kwarg_definition, KwargDefinition):
+                for constraint in _constraint_fields:
+                    if (value := getattr(field_definition.kwarg_definition, constraint)) is not None:
+                        constraints.setdefault(constraint, value)
+        path_parameter_info.append(
+            PathParameterInfo(name=parameter.name, type=parameter.type, required=True, constraints=constraints)
+        )
+    return tuple(path_parameter_info)
+
+
+def get_route_table_rows(route: BaseRoute) -> list[RouteTableRow]:
+    """Describe the route handlers of a route as route table rows.
+
+    Args:
+        route: The route from which the route handlers are extracted.
+
+    Returns:
+        A row for each route handler of the route.
+    """
+    from litestar.utils.helpers import unwrap_partial
+
+    return [
+        RouteTableRow(
+            path=route.path,
+            scope_type=str(getattr(route.scope_type, "value", route.scope_type)),
+            methods=tuple(sorted(getattr(route_handler, "http_methods", ()))),
+            name=route_handler.name,
+            handler=getattr(unwrap_partial(route_handler.fn), "__qualname__", route_handler.handler_name),
+            middleware_count=len(route_handler.resolve_middleware()),
+        )
+        for route_handler in get_route_handlers(route)
+    ]
+
+
+def get_route_signature(route: HTTPRoute | WebSocketRoute | ASGIRoute) -> str:
+    """Describe a route by the keys it is stored under on the routing trie and its path template.
+
+    Args:
+        route: The route to describe.
+
+    Returns:
+        A string such as ``"GET,OPTIONS /users/{user_id:int}"``, ``"websocket /chat"`` or ``"asgi /static static"``.
+    """
+    route_handler_map = get_route_handler_map(route)
+    signature = f"{','.join(sorted(route_handler_map))} {route.path}"
+    if "asgi" in route_handler_map:
+        route_handler = route_handler_map["asgi"]
+        if getattr(route_handler, "is_static", False):
+            signature += " static"
+        elif getattr(route_handler, "is_mount", False):
+            signature += " mount"
+    return signature
+
+
+def get_synthetic_path(route: BaseRoute) -> str:
+    """Render a request path matching a route, using placeholder values for its path parameters.
+
+    Args:
+        route: The route to render a path for.
+
+    Returns:
+        A request path.
+    """
+    components: list[str] = []
+    for component in route.path_components:
+        if isinstance(component, PathSegmentPattern):
+            components.append(
+                component.render(_synthetic_path_parameter_values.get(p.type, "warm-up") for p in component.parameters)
+            )
+        elif isinstance(component, PathParameterDefinition):
+            components.append(_synthetic_path_parameter_values.get(component.type, "warm-up"))
+        else:
+            components.append(component)
+    return "/" + "/".join(components)
+
+
+def warm_up_route_handler(route_handler: RouteHandlerType) -> None:
+    """Call the memoized resolvers of a route handler, so they are not computed when the first request is handled.
+
+    Args:
+        route_handler: The route handler to warm up.
+
+    Returns:
+        None
+    """
+    for resolver_name in _route_handler_resolvers:
+        if resolver := getattr(route_handler, resolver_name, None):
+            resolver()
@@ -7,6 +7,7 @@ from litestar._openapi.parameters import create_parameters_for_handler
 from litestar._openapi.request_body import create_request_body
 from litestar._openapi.responses import create_responses_for_handler
 from litestar._openapi.utils import SEPARATORS_CLEANUP_PATTERN
+from litestar.constants import WILDCARD_HTTP_METHOD
 from litestar.enums import HttpMethod
 from litestar.openapi.spec import Operation, PathItem
 from litestar.utils.helpers import unwrap_partial
@@ -36,7 +37,8 @@ class PathItemFactory:
         for http_method, handler_tuple in self.route.route_handler_map.items():
             route_handler, _ = handler_tuple
 
-            if not route_handler.resolve_include_in_schema():
+            # OpenAPI has no way to describe an operation handling any method
+            if http_method == WILDCARD_HTTP_METHOD or not route_handler.resolve_include_in_schema():
                 continue
 
             operation = self.create_operation_for_handler_method(route_handler, HttpMethod(http_method))
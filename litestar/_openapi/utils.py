@@ -3,7 +3,7 @@ from __future__ import annotations
 import re
 from typing import TYPE_CHECKING
 
-from litestar.types.internal_types import PathParameterDefinition
+from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
 
 if TYPE_CHECKING:
     from litestar.handlers.http_handlers import HTTPRouteHandler
@@ -18,7 +18,7 @@ SEPARATORS_CLEANUP_PATTERN = re.compile(r"[!#$%&'*+\-.^_`|~:]+")
 def default_operation_id_creator(
     route_handler: HTTPRouteHandler,
     http_method: Method,
-    path_components: list[str | PathParameterDefinition],
+    path_components: list[str | PathParameterDefinition | PathSegmentPattern],
 ) -> str:
     """Create a unique 'operationId' for an OpenAPI PathItem entry.
 
@@ -39,8 +39,16 @@ def default_operation_id_creator(
     )
 
     components_namespace = ""
-    for component in (c.name if isinstance(c, PathParameterDefinition) else c for c in path_components):
+    for component in (_get_component_name(c) for c in path_components):
         if component.title() not in components_namespace:
             components_namespace += component.title()
 
     return SEPARATORS_CLEANUP_PATTERN.sub("", components_namespace + handler_namespace)
+
+
+def _get_component_name(component: str | PathParameterDefinition | PathSegmentPattern) -> str:
+    if isinstance(component, PathParameterDefinition):
+        return component.name
+    if isinstance(component, PathSegmentPattern):
+        return "".join(parameter.name.title() for parameter in component.parameters)
+    return component
@@ -3,16 +3,15 @@ from __future__ import annotations
 import inspect
 import logging
 import os
+import unicodedata
 from contextlib import (
     AbstractAsyncContextManager,
     AsyncExitStack,
     asynccontextmanager,
     suppress,
 )
-from datetime import date, datetime, time, timedelta
 from functools import partial
 from itertools import chain
-from pathlib import Path
 from typing import TYPE_CHECKING, Any, AsyncGenerator, Callable, Iterable, Mapping, Sequence, TypedDict, cast
 
 from litestar._asgi import ASGIRouter
@@ -46,7 +45,7 @@ from litestar.routes import ASGIRoute, HTTPRoute, WebSocketRoute
 from litestar.static_files.base import StaticFiles
 from litestar.stores.registry import StoreRegistry
 from litestar.types import Empty, TypeDecodersSequence
-from litestar.types.internal_types import PathParameterDefinition, TemplateConfigType
+from litestar.types.internal_types import TemplateConfigType
 from litestar.utils import deprecated, ensure_async_callable, join_paths, unique
 from litestar.utils.dataclass import extract_dataclass_items
 from litestar.utils.predicates import is_async_callable
@@ -59,6 +58,7 @@ if TYPE_CHECKING:
     from litestar.config.compression import CompressionConfig
     from litestar.config.cors import CORSConfig
     from litestar.config.csrf import CSRFConfig
+    from litestar.config.versioning import VersioningConfig
     from litestar.datastructures import CacheControlHeader, ETag
     from litestar.dto import AbstractDTO
     from litestar.events.listener import EventListener
@@ -79,6 +79,7 @@ if TYPE_CHECKING:
         ControllerRouterHandler,
         Dependencies,
         EmptyType,
+        EncodedSlashHandling,
         ExceptionHandlersMap,
         GetLogger,
         Guard,
@@ -162,6 +163,12 @@ class Litestar(Router):
         "stores",
         "template_engine",
         "pdb_on_exception",
+        "redirect_to_canonical_path",
+        "head_falls_back_to_get",
+        "versioning_config",
+        "encoded_slash_handling",
+        "normalize_unicode_paths",
+        "route_on_raw_path",
         "experimental_features",
     )
 
@@ -219,6 +226,12 @@ class Litestar(Router):
         | None = None,
         pdb_on_exception: bool | None = None,
         experimental_features: Iterable[ExperimentalFeatures] | None = None,
+        redirect_to_canonical_path: bool = False,
+        head_falls_back_to_get: bool = False,
+        versioning_config: VersioningConfig | None = None,
+        encoded_slash_handling: EncodedSlashHandling = "split",
+        normalize_unicode_paths: bool = False,
+        route_on_raw_path: bool = False,
     ) -> None:
         """Initialize a ``Litestar`` application.
 
@@ -310,6 +323,22 @@ class Litestar(Router):
             websocket_class: An optional subclass of :class:`WebSocket <.connection.WebSocket>` to use for websocket
                 connections.
             experimental_features: An iterable of experimental features to enable
+            redirect_to_canonical_path: Redirect requests whose paths are not in their canonical form, e.g. because
+                they contain duplicate slashes, a trailing slash or ``..`` segments, to the canonical path with a
+                ``308`` response, instead of routing them as if they had been made to it.
+            head_falls_back_to_get: Serve ``HEAD`` requests to paths without a ``HEAD`` handler with the ``GET``
+                handler of the path, sending its response without a body, instead of responding with a ``405``.
+            versioning_config: If set, enables API version-aware routing, see
+                :class:`VersioningConfig <litestar.config.versioning.VersioningConfig>`.
+            encoded_slash_handling: How to handle encoded slashes (``%2F``) in request paths. With ``"split"``, they
+                are decoded before the path is split into segments, like unencoded slashes. With ``"reject"``, requests
+                whose paths contain them are responded to with a ``404``. With ``"decode"`` and ``"pass_through"``, they
+                do not separate path segments, and are passed to path parameters decoded or as is.
+            normalize_unicode_paths: Apply Unicode normalization form C (NFC) to the paths of routes and requests, so
+                that visually identical paths using different Unicode representations are routed alike.
+            route_on_raw_path: Route requests by the ``raw_path`` of the connection scope instead of the ``path``
+                decoded by the server, so that the path and its path parameters are percent-decoded exactly once, by
+                the router.
         """
 
         if logging_config is Empty:
@@ -371,6 +400,12 @@ class Litestar(Router):
             type_decoders=type_decoders,
             websocket_class=websocket_class,
             experimental_features=list(experimental_features or []),
+            redirect_to_canonical_path=redirect_to_canonical_path,
+            head_falls_back_to_get=head_falls_back_to_get,
+            versioning_config=versioning_config,
+            encoded_slash_handling=encoded_slash_handling,
+            normalize_unicode_paths=normalize_unicode_paths,
+            route_on_raw_path=route_on_raw_path,
         )
 
         config.plugins.extend([OpenAPIPlugin(self), *openapi_schema_plugins])
@@ -417,6 +452,12 @@ class Litestar(Router):
         self.websocket_class: type[WebSocket] = config.websocket_class or WebSocket
         self.debug = config.debug
         self.pdb_on_exception: bool = config.pdb_on_exception
+        self.redirect_to_canonical_path = config.redirect_to_canonical_path
+        self.head_falls_back_to_get = config.head_falls_back_to_get
+        self.versioning_config = config.versioning_config
+        self.encoded_slash_handling = config.encoded_slash_handling
+        self.normalize_unicode_paths = config.normalize_unicode_paths
+        self.route_on_raw_path = config.route_on_raw_path
         self.include_in_schema = include_in_schema
 
         if self.pdb_on_exception:
@@ -660,6 +701,10 @@ class Litestar(Router):
 
         self.asgi_router.construct_routing_trie()
 
+    def _get_route_path(self, route_path: str) -> str:
+        path = super()._get_route_path(route_path)
+        return unicodedata.normalize("NFC", path) if self.normalize_unicode_paths else path
+
     def get_handler_index_by_name(self, name: str) -> HandlerIndex | None:
         """Receives a route handler name and returns an optional dictionary containing the route handler instance and
         list of paths sorted lexically.
@@ -729,41 +774,7 @@ class Litestar(Router):
         Returns:
             A fully formatted url path.
         """
-        handler_index = self.get_handler_index_by_name(name)
-        if handler_index is None:
-            raise NoRouteMatchFoundException(f"Route {name} can not be found")
-
-        allow_str_instead = {datetime, date, time, timedelta, float, Path}
-        routes = sorted(
-            self.asgi_router.route_mapping[handler_index["identifier"]],
-            key=lambda r: len(r.path_parameters),
-            reverse=True,
-        )
-        passed_parameters = set(path_parameters.keys())
-
-        selected_route = next(
-            (
-                route
-                for route in routes
-                if passed_parameters.issuperset({param.name for param in route.path_parameters})
-            ),
-            routes[-1],
-        )
-        output: list[str] = []
-        for component in selected_route.path_components:
-            if isinstance(component, PathParameterDefinition):
-                val = path_parameters.get(component.name)
-                if not isinstance(val, component.type) and (
-                    component.type not in allow_str_instead or not isinstance(val, str)
-                ):
-                    raise NoRouteMatchFoundException(
-                        f"Received type for path parameter {component.name} doesn't match declared type {component.type}"
-                    )
-                output.append(str(val))
-            else:
-                output.append(component)
-
-        return join_paths(output)
+        return self.asgi_router.url_path_for(name, **path_parameters)
 
     @deprecated(
         "2.6.0", info="Use create_static_files router instead of StaticFilesConfig, which works with route_reverse"
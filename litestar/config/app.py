@@ -17,6 +17,7 @@ if TYPE_CHECKING:
     from litestar.config.compression import CompressionConfig
     from litestar.config.cors import CORSConfig
     from litestar.config.csrf import CSRFConfig
+    from litestar.config.versioning import VersioningConfig
     from litestar.connection import Request, WebSocket
     from litestar.datastructures import CacheControlHeader, ETag
     from litestar.di import Provide
@@ -38,6 +39,7 @@ if TYPE_CHECKING:
         BeforeMessageSendHookHandler,
         BeforeRequestHookHandler,
         ControllerRouterHandler,
+        EncodedSlashHandling,
         ExceptionHandlersMap,
         Guard,
         Middleware,
@@ -153,6 +155,34 @@ class AppConfig:
     """A mapping of :class:`Parameter <.params.Parameter>` definitions available to all application paths."""
     pdb_on_exception: bool = field(default=False)
     """Drop into the PDB on an exception"""
+    redirect_to_canonical_path: bool = field(default=False)
+    """Redirect requests to the canonical form of their path with a ``308`` response, instead of routing them as if they
+    had been made to it. See :func:`canonicalize_path <litestar.utils.path.canonicalize_path>`.
+    """
+    versioning_config: VersioningConfig | None = field(default=None)
+    """If set, enables API version-aware routing, see :class:`VersioningConfig
+    <litestar.config.versioning.VersioningConfig>`.
+    """
+    encoded_slash_handling: EncodedSlashHandling = field(default="split")
+    """How to handle encoded slashes (``%2F``) in request paths.
+
+    - ``"split"``: Decode them before the path is split into segments, like unencoded slashes
+    - ``"reject"``: Respond to requests whose paths contain them with a ``404``
+    - ``"decode"``: Keep them within their path segment, and pass them to path parameters decoded, i.e. as ``/``
+    - ``"pass_through"``: Keep them within their path segment, and pass them to path parameters as is, i.e. as ``%2F``
+    """
+    normalize_unicode_paths: bool = field(default=False)
+    """Apply Unicode normalization form C (NFC) to the paths of routes and requests, so that visually identical paths
+    using different Unicode representations are routed alike.
+    """
+    route_on_raw_path: bool = field(default=False)
+    """Route requests by the ``raw_path`` of the connection scope instead of the ``path`` decoded by the server, so that
+    the path and its path parameters are percent-decoded exactly once, by the router.
+    """
+    head_falls_back_to_get: bool = field(default=False)
+    """Serve ``HEAD`` requests to paths without a ``HEAD`` handler with the ``GET`` handler of the path, sending its
+    response without a body, instead of responding with a ``405``.
+    """
     plugins: list[PluginProtocol] = field(default_factory=list)
     """List of :class:`SerializationPluginProtocol <.plugins.SerializationPluginProtocol>`."""
     request_class: type[Request] | None = field(default=None)
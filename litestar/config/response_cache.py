@@ -1,9 +1,10 @@
 from __future__ import annotations
 
 from dataclasses import dataclass, field
-from typing import TYPE_CHECKING, Any, Callable, final
+from typing import TYPE_CHECKING, Any, Callable, Sequence, final
 from urllib.parse import urlencode
 
+from litestar._parsers import parse_query_string
 from litestar.status_codes import (
     HTTP_200_OK,
     HTTP_300_MULTIPLE_CHOICES,
@@ -15,9 +16,9 @@ if TYPE_CHECKING:
     from litestar import Litestar
     from litestar.connection import Request
     from litestar.stores.base import Store
-    from litestar.types import CacheKeyBuilder, HTTPScope
+    from litestar.types import CacheKeyBuilder, HTTPScope, Scope
 
-__all__ = ("ResponseCacheConfig", "default_cache_key_builder", "CACHE_FOREVER")
+__all__ = ("ResponseCacheConfig", "create_route_cache_key", "default_cache_key_builder", "CACHE_FOREVER")
 
 
 @final
@@ -42,6 +43,32 @@ def default_cache_key_builder(request: Request[Any, Any, Any]) -> str:
     return request.method + request.url.path + urlencode(query_params, doseq=True)
 
 
+def create_route_cache_key(scope: Scope, path_template: str, query_params: Sequence[str] | None = None) -> str:
+    """Create a cache key from the routing information of a connection.
+
+    Unlike :func:`default_cache_key_builder`, the key does not depend on the raw path, but on the path template of the
+    matched route and the parsed path parameters, so that paths routed to the same parameters share a key. If the
+    handler of a path is selected by the ``Accept`` or ``Host`` header or by the requested API version, the router
+    appends the media type, host or version of the selected handler to the key.
+
+    Args:
+        scope: The ASGI connection scope, after routing.
+        path_template: The path template of the matched route.
+        query_params: The names of the query parameters to include in the key. If ``None``, all are included.
+
+    Returns:
+        A combination of the method, the path template, the sorted path parameters and the sorted query parameters.
+    """
+    path_params = sorted((name, str(value)) for name, value in scope["path_params"].items())
+    query = [
+        (name, value)
+        for name, value in parse_query_string(scope.get("query_string", b""))
+        if query_params is None or name in query_params
+    ]
+    query.sort(key=lambda x: x[0])
+    return " ".join((scope.get("method", ""), path_template, urlencode(path_params), urlencode(query)))
+
+
 def default_do_cache_predicate(_: HTTPScope, status_code: int) -> bool:
     """Given a status code, returns a boolean indicating whether the response should be cached.
 
@@ -75,6 +102,13 @@ class ResponseCacheConfig:
     cache_response_filter: Callable[[HTTPScope, int], bool] = field(default=default_do_cache_predicate)
     """A callable that receives connection scope and a status code, and returns a boolean indicating whether the
     response should be cached."""
+    key_from_route: bool = False
+    """Whether the router creates the cache keys of cached routes while resolving them, using
+    :func:`create_route_cache_key`. This keeps cache keys consistent with the normalization applied by routing, e.g.
+    ``/items/01`` and ``/items/1/`` share a key if routed to ``/items/{item_id:int}``. Takes precedence over
+    :attr:`key_builder`, but not over the ``cache_key_builder`` of a route handler."""
+    key_query_params: Sequence[str] | None = None
+    """The query parameters to include in the cache keys created if :attr:`key_from_route` is set. Defaults to all."""
 
     def get_store_from_app(self, app: Litestar) -> Store:
         """Get the store defined in :attr:`store` from an :class:`Litestar <.app.Litestar>` instance."""
@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING
+
+from litestar.exceptions import ImproperlyConfiguredException
+
+__all__ = ("VersioningConfig",)
+
+
+if TYPE_CHECKING:
+    from litestar.types import Scope
+
+
+@dataclass
+class VersioningConfig:
+    """Configuration for API version-aware routing.
+
+    To enable version-aware routing, pass an instance of this class to the :class:`Litestar <litestar.app.Litestar>`
+    constructor using the ``versioning_config`` key. HTTP route handlers can then declare the API version they serve
+    with the ``version`` key of their ``opt``, set directly or via a router. Handlers sharing a path and method are
+    selected by the version requested by a connection, falling back to the handler not declaring a version.
+    """
+
+    versions: list[str]
+    """The known API versions, e.g. ``["v1", "v2"]``."""
+    header: str | None = field(default=None)
+    """The name of a header holding the requested API version, e.g. ``X-API-Version``."""
+    path_prefix: bool = field(default=False)
+    """Whether the requested API version can be given by the first segment of the request path, e.g. ``/v2/items``.
+
+    The segment is moved from the ``path`` of the connection scope into its ``root_path`` before routing, so that
+    ``/v2/items`` is routed to the handlers of ``/items``.
+    """
+    default_version: str | None = field(default=None)
+    """The API version of connections not requesting a known version."""
+
+    def __post_init__(self) -> None:
+        """Ensure that the configuration allows requesting a version."""
+        if not self.header and not self.path_prefix:
+            raise ImproperlyConfiguredException("Either a version header or path prefix versioning must be enabled")
+        if self.default_version is not None and self.default_version not in self.versions:
+            raise ImproperlyConfiguredException(
+                f"Default version {self.default_version!r} is not one of the versions {self.versions!r}"
+            )
+
+    def get_requested_version(self, scope: Scope, path: str) -> tuple[str | None, str]:
+        """Determine the API version requested by a connection.
+
+        A version given by the version header takes precedence over a version given by the path.
+
+        Args:
+            scope: The ASGI connection scope.
+            path: The path of the connection, relative to its ``root_path``.
+
+        Returns:
+            A tuple of the requested version, or the default version if no known version is requested, and the
+            version path prefix, or an empty string if the path does not start with a version.
+        """
+        prefix = ""
+        version: str | None = None
+        if self.path_prefix:
+            first_segment = path.split("/", 2)[1]
+            if first_segment in self.versions:
+                prefix = f"/{first_segment}"
+                version = first_segment
+        if self.header:
+            encoded_header = self.header.lower().encode("latin-1")
+            header_value = next(
+                (value.decode("latin-1") for name, value in scope.get("headers", []) if name == encoded_header), None
+            )
+            if header_value in self.versions:
+                version = header_value
+        return version or self.default_version, prefix
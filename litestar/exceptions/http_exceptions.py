@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from http import HTTPStatus
-from typing import Any
+from typing import Any, Iterable
 
 from litestar.exceptions.base_exceptions import LitestarException
 from litestar.status_codes import (
@@ -25,6 +25,7 @@ __all__ = (
     "NotAuthorizedException",
     "NotFoundException",
     "PermissionDeniedException",
+    "RouteConflictException",
     "ServiceUnavailableException",
     "TemplateNotFoundException",
     "TooManyRequestsException",
@@ -85,6 +86,60 @@ class ImproperlyConfiguredException(HTTPException, ValueError):
     """Application has improper configuration."""
 
 
+class RouteConflictException(ImproperlyConfiguredException):
+    """A route handler conflicts with another route handler registered on the application."""
+
+    path: str
+    """The path template of the route of the handler."""
+    handler_name: str
+    """The qualified name of the handler."""
+    handler_location: str | None
+    """The source location of the handler as ``file:line``, if it can be determined."""
+    conflicting_path: str
+    """The path template of the route of the conflicting handler."""
+    conflicting_handler_name: str
+    """The qualified name of the conflicting handler."""
+    conflicting_handler_location: str | None
+    """The source location of the conflicting handler as ``file:line``, if it can be determined."""
+
+    def __init__(
+        self,
+        *args: Any,
+        path: str,
+        handler_name: str,
+        conflicting_path: str,
+        conflicting_handler_name: str,
+        handler_location: str | None = None,
+        conflicting_handler_location: str | None = None,
+        detail: str = "",
+        status_code: int | None = None,
+        headers: dict[str, str] | None = None,
+        extra: dict[str, Any] | list[Any] | None = None,
+    ) -> None:
+        """Initialize ``RouteConflictException``.
+
+        Args:
+            *args: if ``detail`` kwarg not provided, first arg should be error detail.
+            path: The path template of the route of the handler.
+            handler_name: The qualified name of the handler.
+            conflicting_path: The path template of the route of the conflicting handler.
+            conflicting_handler_name: The qualified name of the conflicting handler.
+            handler_location: The source location of the handler.
+            conflicting_handler_location: The source location of the conflicting handler.
+            detail: Exception details or message. Will default to args[0] if not provided.
+            status_code: Exception HTTP status code.
+            headers: Headers to set on the response.
+            extra: An extra mapping to attach to the exception.
+        """
+        self.path = path
+        self.handler_name = handler_name
+        self.handler_location = handler_location
+        self.conflicting_path = conflicting_path
+        self.conflicting_handler_name = conflicting_handler_name
+        self.conflicting_handler_location = conflicting_handler_location
+        super().__init__(*args, detail=detail, status_code=status_code, headers=headers, extra=extra)
+
+
 class ClientException(HTTPException):
     """Client error."""
 
@@ -117,6 +172,33 @@ class MethodNotAllowedException(ClientException):
     """Server knows the request method, but the target resource doesn't support this method."""
 
     status_code = HTTP_405_METHOD_NOT_ALLOWED
+    allowed_methods: frozenset[str]
+    """The methods supported by the target resource."""
+
+    def __init__(
+        self,
+        *args: Any,
+        allowed_methods: Iterable[str] | None = None,
+        detail: str = "",
+        status_code: int | None = None,
+        headers: dict[str, str] | None = None,
+        extra: dict[str, Any] | list[Any] | None = None,
+    ) -> None:
+        """Initialize ``MethodNotAllowedException``.
+
+        Args:
+            *args: if ``detail`` kwarg not provided, first arg should be error detail.
+            allowed_methods: The methods supported by the target resource. If given, they are listed in the ``Allow``
+                header of the response.
+            detail: Exception details or message. Will default to args[0] if not provided.
+            status_code: Exception HTTP status code.
+            headers: Headers to set on the response.
+            extra: An extra mapping to attach to the exception.
+        """
+        self.allowed_methods = frozenset(allowed_methods or ())
+        if self.allowed_methods:
+            headers = {**(headers or {}), "Allow": ", ".join(sorted(self.allowed_methods))}
+        super().__init__(*args, detail=detail, status_code=status_code, headers=headers, extra=extra)
 
 
 class TooManyRequestsException(ClientException):
@@ -6,7 +6,7 @@ from typing import TYPE_CHECKING, Any, AnyStr, cast
 from anyio import AsyncFile, Path, open_file
 
 from litestar.concurrency import sync_to_thread
-from litestar.exceptions import InternalServerException, NotAuthorizedException
+from litestar.exceptions import ImproperlyConfiguredException, InternalServerException, NotAuthorizedException
 from litestar.types.file_types import FileSystemProtocol
 from litestar.utils.predicates import is_async_callable
 
@@ -38,6 +38,25 @@ class BaseLocalFileSystem(FileSystemProtocol):
         result = await Path(path).stat()
         return await FileSystemAdapter.parse_stat_result(path=path, result=result)
 
+    async def ls(self, path: PathType, detail: bool = True, **kwargs: Any) -> list[FileInfo]:
+        """List the entries of a directory, like the ``ls`` method of the file systems of ``fsspec``.
+
+        Args:
+            path: A directory path.
+            detail: Ignored, the info of the entries is always returned.
+            **kwargs: Any additional kwargs.
+
+        Returns:
+            A list of the file info of the entries. Broken symbolic links are omitted.
+        """
+        entries: list[FileInfo] = []
+        async for entry in Path(path).iterdir():
+            try:
+                entries.append(await self.info(entry))
+            except FileNotFoundError:
+                continue
+        return entries
+
     async def open(self, file: PathType, mode: str, buffering: int = -1) -> AsyncFile[AnyStr]:  # pyright: ignore
         """Return a file-like object from the filesystem.
 
@@ -87,6 +106,33 @@ class FileSystemAdapter:
         except OSError as e:  # pragma: no cover
             raise InternalServerException from e
 
+    async def ls(self, path: PathType) -> list[FileInfo]:
+        """Proxies the call to the underlying FS Spec's ``ls`` method, ensuring it's done in an async fashion and with
+        strong typing.
+
+        Args:
+            path: A directory path to list the entries of.
+
+        Raises:
+            ImproperlyConfiguredException: If the file system does not provide an ``ls`` method.
+
+        Returns:
+            A list of the file info of the entries.
+        """
+        if not callable(ls := getattr(self.file_system, "ls", None)):
+            raise ImproperlyConfiguredException("file_system must provide an 'ls' method to list directories")
+        try:
+            awaitable = (
+                ls(str(path), detail=True) if is_async_callable(ls) else sync_to_thread(ls, str(path), detail=True)
+            )
+            return cast("list[FileInfo]", await awaitable)
+        except FileNotFoundError as e:
+            raise e
+        except PermissionError as e:
+            raise NotAuthorizedException(f"failed to list {path} due to missing permissions") from e
+        except OSError as e:  # pragma: no cover
+            raise InternalServerException from e
+
     async def open(
         self,
         file: PathType,
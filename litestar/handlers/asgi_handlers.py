@@ -53,7 +53,8 @@ class ASGIRouteHandler(BaseRouteHandler):
             is_mount: A boolean dictating whether the handler's paths should be regarded as mount paths. Mount path
                 accept any arbitrary paths that begin with the defined prefixed path. For example, a mount with the path
                 ``/some-path/`` will accept requests for ``/some-path/`` and any sub path under this, e.g.
-                ``/some-path/sub-path/`` etc.
+                ``/some-path/sub-path/`` etc. The mount path is appended to the ``root_path`` of the scope passed to
+                the handler, and removed from its ``path``.
             is_static: A boolean dictating whether the handler's paths should be regarded as static paths. Static paths
                 are used to deliver static files.
             signature_namespace: A mapping of names to types for use in forward reference resolution during signature modelling.
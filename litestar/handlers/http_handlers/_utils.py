@@ -2,8 +2,9 @@ from __future__ import annotations
 
 from functools import lru_cache
 from inspect import isawaitable
-from typing import TYPE_CHECKING, Any, Sequence, cast
+from typing import TYPE_CHECKING, Any, Literal, Sequence, cast
 
+from litestar.constants import WILDCARD_HTTP_METHOD
 from litestar.enums import HttpMethod
 from litestar.exceptions import ValidationException
 from litestar.response import Response
@@ -163,9 +164,13 @@ def create_response_handler(
     return handler
 
 
-def normalize_http_method(http_methods: HttpMethod | Method | Sequence[HttpMethod | Method]) -> set[Method]:
+def normalize_http_method(
+    http_methods: HttpMethod | Method | Literal["*"] | Sequence[HttpMethod | Method | Literal["*"]],
+) -> set[Method]:
     """Normalize HTTP method(s) into a set of upper-case method names.
 
+    The wildcard method ``"*"`` is passed through as is.
+
     Args:
         http_methods: A value for http method.
 
@@ -179,7 +184,7 @@ def normalize_http_method(http_methods: HttpMethod | Method | Sequence[HttpMetho
 
     for method in http_methods:
         method_name = method.value.upper() if isinstance(method, HttpMethod) else method.upper()
-        if method_name not in HTTP_METHOD_NAMES:
+        if method_name not in HTTP_METHOD_NAMES and method_name != WILDCARD_HTTP_METHOD:
             raise ValidationException(f"Invalid HTTP method: {method_name}")
         output.add(method_name)
 
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from enum import Enum
-from typing import TYPE_CHECKING, AnyStr, Mapping, Sequence, TypedDict, cast
+from typing import TYPE_CHECKING, AnyStr, Literal, Mapping, Sequence, TypedDict, cast
 
 from litestar._layers.utils import narrow_response_cookies, narrow_response_headers
 from litestar.connection import Request
@@ -92,6 +92,7 @@ class HTTPRouteHandler(BaseRouteHandler):
         "deprecated",
         "description",
         "etag",
+        "has_declared_media_type",
         "has_sync_callable",
         "http_methods",
         "include_in_schema",
@@ -131,7 +132,7 @@ class HTTPRouteHandler(BaseRouteHandler):
         etag: ETag | None = None,
         exception_handlers: ExceptionHandlersMap | None = None,
         guards: Sequence[Guard] | None = None,
-        http_method: HttpMethod | Method | Sequence[HttpMethod | Method],
+        http_method: HttpMethod | Method | Literal["*"] | Sequence[HttpMethod | Method | Literal["*"]],
         media_type: MediaType | str | None = None,
         middleware: Sequence[Middleware] | None = None,
         name: str | None = None,
@@ -192,7 +193,8 @@ class HTTPRouteHandler(BaseRouteHandler):
             guards: A sequence of :class:`Guard <.types.Guard>` callables.
             http_method: An :class:`http method string <.types.Method>`, a member of the enum
                 :class:`HttpMethod <.enums.HttpMethod>` or a list of these that correlates to the methods the route
-                handler function should handle.
+                handler function should handle. ``"*"`` makes the handler handle any method no other handler of the
+                path is registered for, instead of responding with ``405``.
             media_type: A member of the :class:`MediaType <.enums.MediaType>` enum or a string with a valid IANA
                 Media-Type.
             middleware: A sequence of :class:`Middleware <.types.Middleware>`.
@@ -265,6 +267,9 @@ class HTTPRouteHandler(BaseRouteHandler):
         self.cache_key_builder = cache_key_builder
         self.etag = etag
         self.media_type: MediaType | str = media_type or ""
+        # a media type inferred from the return annotation does not make the handler an alternative for content
+        # negotiation
+        self.has_declared_media_type = bool(media_type)
         self.request_class = request_class
         self.response_class = response_class
         self.response_cookies: Sequence[Cookie] | None = narrow_response_cookies(response_cookies)
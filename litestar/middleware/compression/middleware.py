@@ -112,6 +112,7 @@ class CompressionMiddleware(AbstractMiddleware):
 
         initial_message: HTTPResponseStartEvent | None = None
         started = False
+        is_encoded = False
 
         connection_state = ScopeState.from_scope(scope)
 
@@ -123,9 +124,19 @@ class CompressionMiddleware(AbstractMiddleware):
             """
             nonlocal started
             nonlocal initial_message
+            nonlocal is_encoded
 
             if message["type"] == "http.response.start":
                 initial_message = message
+                # responses that are already encoded, e.g. precompressed static files, are sent as they are
+                is_encoded = "content-encoding" in MutableScopeHeaders(initial_message)
+                return
+
+            if is_encoded:
+                if initial_message is not None:
+                    await send(initial_message)
+                    initial_message = None
+                await send(message)
                 return
 
             if initial_message is not None and value_or_default(connection_state.is_cached, False):
@@ -0,0 +1,67 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from anyio import Semaphore
+
+from litestar.exceptions import ServiceUnavailableException
+from litestar.middleware.base import MiddlewareProtocol
+
+__all__ = ("ConcurrencyLimitMiddleware",)
+
+
+if TYPE_CHECKING:
+    from litestar.types import ASGIApp, Receive, Scope, Send
+
+
+class ConcurrencyLimitMiddleware(MiddlewareProtocol):
+    """Middleware limiting the number of connections a route handles concurrently.
+
+    It is applied automatically to route handlers declaring a ``max_concurrency`` key in their ``opt``, e.g.
+    ``@get("/", max_concurrency=10)``. Excess connections are rejected with a ``503 Service Unavailable`` response, or
+    wait for a slot if the handler's ``opt`` also sets ``queue_excess`` to ``True``. The limit applies separately to
+    each HTTP method of a handler.
+    """
+
+    __slots__ = ("app", "in_flight", "max_concurrency", "semaphore")
+
+    def __init__(self, app: ASGIApp, max_concurrency: int, queue_excess: bool = False) -> None:
+        """Initialize ``ConcurrencyLimitMiddleware``.
+
+        Args:
+            app: The ``next`` ASGI app to call.
+            max_concurrency: The maximum number of connections handled concurrently.
+            queue_excess: Whether excess connections wait for a slot instead of being rejected.
+        """
+        self.app = app
+        self.in_flight = 0
+        self.max_concurrency = max_concurrency
+        self.semaphore = Semaphore(max_concurrency) if queue_excess else None
+
+    async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
+        """ASGI callable.
+
+        Args:
+            scope: The ASGI connection scope.
+            receive: The ASGI receive function.
+            send: The ASGI send function.
+
+        Raises:
+            ServiceUnavailableException: If the maximum number of concurrent connections is reached.
+
+        Returns:
+            None
+        """
+        if self.semaphore is not None:
+            async with self.semaphore:
+                await self.app(scope, receive, send)
+            return
+
+        if self.in_flight >= self.max_concurrency:
+            raise ServiceUnavailableException("Too many concurrent requests")
+
+        self.in_flight += 1
+        try:
+            await self.app(scope, receive, send)
+        finally:
+            self.in_flight -= 1
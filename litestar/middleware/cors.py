@@ -1,12 +1,15 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Iterable
 
+from litestar.constants import DEFAULT_ALLOWED_CORS_HEADERS
 from litestar.datastructures import Headers, MutableScopeHeaders
-from litestar.enums import ScopeType
-from litestar.middleware.base import AbstractMiddleware
+from litestar.enums import MediaType, ScopeType
+from litestar.middleware.base import AbstractMiddleware, MiddlewareProtocol
+from litestar.response.base import ASGIResponse
+from litestar.status_codes import HTTP_204_NO_CONTENT, HTTP_400_BAD_REQUEST
 
-__all__ = ("CORSMiddleware",)
+__all__ = ("CORSMiddleware", "CORSPreflightMiddleware", "validate_preflight_request")
 
 
 if TYPE_CHECKING:
@@ -14,6 +17,61 @@ if TYPE_CHECKING:
     from litestar.types import ASGIApp, Message, Receive, Scope, Send
 
 
+def validate_preflight_request(
+    config: CORSConfig, request_headers: Headers, origin: str, allowed_methods: Iterable[str] | None = None
+) -> tuple[list[str], dict[str, str]]:
+    """Validate a CORS preflight request against a CORS config.
+
+    Args:
+        config: The CORS config to validate against.
+        request_headers: The headers of the preflight request.
+        origin: The value of the ``Origin`` header.
+        allowed_methods: The methods handled by the requested route. If given, only those of them allowed by
+            ``config`` are allowed.
+
+    Returns:
+        A tuple of the names of the failed checks and the headers to respond with if there are none.
+    """
+    pre_flight_method = request_headers.get("Access-Control-Request-Method")
+    failures = []
+
+    if allowed_methods is not None:
+        allowed_methods = {
+            method for method in allowed_methods if config.is_allow_all_methods or method in config.allow_methods
+        }
+
+    if pre_flight_method and (
+        (not config.is_allow_all_methods and pre_flight_method not in config.allow_methods)
+        or (allowed_methods is not None and pre_flight_method not in allowed_methods)
+    ):
+        failures.append("method")
+
+    response_headers = config.preflight_headers.copy()
+    if allowed_methods is not None:
+        response_headers["Access-Control-Allow-Methods"] = ", ".join(sorted(allowed_methods))
+
+    if not config.is_origin_allowed(origin):
+        failures.append("Origin")
+    elif response_headers.get("Access-Control-Allow-Origin") != "*":
+        response_headers["Access-Control-Allow-Origin"] = origin
+
+    pre_flight_requested_headers = [
+        header.strip()
+        for header in request_headers.get("Access-Control-Request-Headers", "").split(",")
+        if header.strip()
+    ]
+
+    if pre_flight_requested_headers:
+        if config.is_allow_all_headers:
+            response_headers["Access-Control-Allow-Headers"] = ", ".join(
+                sorted(set(pre_flight_requested_headers) | DEFAULT_ALLOWED_CORS_HEADERS)  # pyright: ignore
+            )
+        elif any(header.lower() not in config.allow_headers for header in pre_flight_requested_headers):
+            failures.append("headers")
+
+    return failures, response_headers
+
+
 class CORSMiddleware(AbstractMiddleware):
     """CORS Middleware."""
 
@@ -80,3 +138,58 @@ class CORSMiddleware(AbstractMiddleware):
             await send(message)
 
         return wrapped_send
+
+
+class CORSPreflightMiddleware(MiddlewareProtocol):
+    """Middleware answering the CORS preflight requests of a route according to a route specific CORS config.
+
+    It is applied automatically to the ``OPTIONS`` handler of routes having a handler with a ``cors_config`` key in its
+    ``opt``, e.g. ``Router(path="/api", route_handlers=[...], opt={"cors_config": CORSConfig(allow_origins=[...])})``.
+    Since it wraps the route's middleware stack, preflight requests are answered before any middleware runs. Other
+    ``OPTIONS`` requests are passed on to the route.
+    """
+
+    __slots__ = ("allowed_methods", "app", "config")
+
+    def __init__(self, app: ASGIApp, config: CORSConfig, allowed_methods: Iterable[str]) -> None:
+        """Initialize ``CORSPreflightMiddleware``.
+
+        Args:
+            app: The ``next`` ASGI app to call.
+            config: The CORS config of the route.
+            allowed_methods: The methods handled by the route.
+        """
+        self.app = app
+        self.config = config
+        self.allowed_methods = frozenset(allowed_methods)
+
+    async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
+        """ASGI callable.
+
+        Args:
+            scope: The ASGI connection scope.
+            receive: The ASGI receive function.
+            send: The ASGI send function.
+
+        Returns:
+            None
+        """
+        headers = Headers.from_scope(scope=scope)
+        origin = headers.get("origin")
+        if scope["type"] != ScopeType.HTTP or not origin or "access-control-request-method" not in headers:
+            await self.app(scope, receive, send)
+            return
+
+        failures, response_headers = validate_preflight_request(
+            config=self.config, request_headers=headers, origin=origin, allowed_methods=self.allowed_methods
+        )
+        response = (
+            ASGIResponse(
+                body=f"Disallowed CORS {', '.join(failures)}",
+                status_code=HTTP_400_BAD_REQUEST,
+                media_type=MediaType.TEXT,
+            )
+            if failures
+            else ASGIResponse(status_code=HTTP_204_NO_CONTENT, media_type=MediaType.TEXT, headers=response_headers)
+        )
+        await response(scope, receive, send)
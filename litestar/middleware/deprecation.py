@@ -0,0 +1,76 @@
+from __future__ import annotations
+
+from datetime import date, datetime, timezone
+from email.utils import format_datetime
+from typing import TYPE_CHECKING
+
+from litestar.constants import HTTP_RESPONSE_START
+from litestar.datastructures import MutableScopeHeaders
+from litestar.enums import ScopeType
+from litestar.middleware.base import MiddlewareProtocol
+
+__all__ = ("DeprecationMiddleware",)
+
+
+if TYPE_CHECKING:
+    from litestar.types import ASGIApp, Message, Receive, Scope, Send
+
+
+def _format_http_date(value: date | datetime) -> str:
+    if not isinstance(value, datetime):
+        value = datetime(value.year, value.month, value.day, tzinfo=timezone.utc)
+    elif value.tzinfo is None:
+        value = value.replace(tzinfo=timezone.utc)
+    return format_datetime(value.astimezone(timezone.utc), usegmt=True)
+
+
+class DeprecationMiddleware(MiddlewareProtocol):
+    """Middleware adding ``Deprecation``, ``Sunset`` and ``Link`` headers to the responses of a deprecated route.
+
+    It is applied automatically to route handlers declared with ``deprecated=True``. The sunset date and link are read
+    from the ``sunset`` and ``deprecation_link`` keys of the handler's ``opt``, e.g.
+    ``@get("/", deprecated=True, sunset=date(2025, 1, 1), deprecation_link="https://example.org/migration")``.
+    """
+
+    __slots__ = ("app", "headers")
+
+    def __init__(self, app: ASGIApp, sunset: date | datetime | None = None, link: str | None = None) -> None:
+        """Initialize ``DeprecationMiddleware``.
+
+        Args:
+            app: The ``next`` ASGI app to call.
+            sunset: The date after which the route is expected to become unresponsive. Naive datetimes are assumed to
+                be in UTC.
+            link: A URL of documentation about the deprecation, e.g. a migration guide.
+        """
+        self.app = app
+        self.headers = [("deprecation", "true")]
+        if sunset is not None:
+            self.headers.append(("sunset", _format_http_date(sunset)))
+        if link is not None:
+            self.headers.append(("link", f'<{link}>; rel="deprecation"'))
+
+    async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
+        """ASGI callable.
+
+        Args:
+            scope: The ASGI connection scope.
+            receive: The ASGI receive function.
+            send: The ASGI send function.
+
+        Returns:
+            None
+        """
+        if scope["type"] != ScopeType.HTTP:
+            await self.app(scope, receive, send)
+            return
+
+        async def send_wrapper(message: Message) -> None:
+            if message["type"] == HTTP_RESPONSE_START:
+                message.setdefault("headers", [])
+                headers = MutableScopeHeaders.from_message(message)
+                for name, value in self.headers:
+                    headers.add(name, value)
+            await send(message)
+
+        await self.app(scope, receive, send_wrapper)
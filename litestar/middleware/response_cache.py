@@ -50,7 +50,9 @@ class ResponseCacheMiddleware(AbstractMiddleware):
                     messages.append(message)
 
                 if messages and message["type"] == HTTP_RESPONSE_BODY and not message["more_body"]:
-                    key = (route_handler.cache_key_builder or self.config.key_builder)(Request(scope))
+                    key = value_or_default(connection_state.cache_key, None) or (
+                        route_handler.cache_key_builder or self.config.key_builder
+                    )(Request(scope))
                     store = self.config.get_store_from_app(scope["app"])
                     await store.set(key, encode_msgpack(messages), expires_in=expires_in)
             await send(message)
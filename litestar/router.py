@@ -217,26 +217,19 @@ class Router:
         routes: list[BaseRoute] = []
 
         for route_path, handlers_map in self.get_route_handler_map(value=validated_value).items():
-            path = join_paths([self.path, route_path])
+            path = self._get_route_path(route_path)
             if http_handlers := unique(
                 [handler for handler in handlers_map.values() if isinstance(handler, HTTPRouteHandler)]
             ):
-                if existing_handlers := unique(
-                    [
-                        handler
-                        for handler in self.route_handler_method_map.get(path, {}).values()
-                        if isinstance(handler, HTTPRouteHandler)
-                    ]
-                ):
-                    http_handlers.extend(existing_handlers)
-                    existing_route_index = find_index(self.routes, lambda x: x.path == path)  # noqa: B023
-
-                    if existing_route_index == -1:  # pragma: no cover
-                        raise ImproperlyConfiguredException("unable to find_index existing route index")
-
+                existing_route_index = find_index(
+                    self.routes, lambda x: x.path == path and isinstance(x, HTTPRoute)  # noqa: B023
+                )
+                if existing_route_index != -1:
+                    # existing handlers come first, since they take precedence for media type based dispatching
+                    existing_route = cast("HTTPRoute", self.routes[existing_route_index])
                     route: WebSocketRoute | ASGIRoute | HTTPRoute = HTTPRoute(
                         path=path,
-                        route_handlers=http_handlers,
+                        route_handlers=[*existing_route.route_handlers, *http_handlers],
                     )
                     self.routes[existing_route_index] = route
                 else:
@@ -310,6 +303,10 @@ class Router:
 
         return handlers_map
 
+    def _get_route_path(self, route_path: str) -> str:
+        """Get the path of a route registered on the router from the path it has been declared with."""
+        return join_paths([self.path, route_path])
+
     def _validate_registration_value(self, value: ControllerRouterHandler) -> Controller | RouteHandlerType | Router:
         """Ensure values passed to the register method are supported."""
         if is_class_and_subclass(value, Controller):
@@ -1,19 +1,23 @@
 from __future__ import annotations
 
 import re
+import sys
 from abc import ABC, abstractmethod
 from datetime import date, datetime, time, timedelta
 from decimal import Decimal
+from itertools import chain
 from pathlib import Path
 from typing import TYPE_CHECKING, Any, Callable
+from urllib.parse import unquote
 from uuid import UUID
 
 import msgspec
 
 from litestar._kwargs import KwargsModel
 from litestar.exceptions import ImproperlyConfiguredException
-from litestar.types.internal_types import PathParameterDefinition
-from litestar.utils import join_paths, normalize_path
+from litestar.types.internal_types import PathParameterDefinition, PathSegmentPattern
+from litestar.utils import join_paths
+from litestar.utils.path import control_characters_pattern, normalize_route_path
 
 if TYPE_CHECKING:
     from litestar.enums import ScopeType
@@ -41,6 +45,8 @@ def _parse_timedelta(value: str) -> timedelta:
 
 
 param_match_regex = re.compile(r"{(.*?)}")
+segment_param_match_regex = re.compile(r"{([^{}]*)}")
+path_component_regex = re.compile(r"[^/]+")
 
 param_type_map = {
     "str": str,
@@ -67,6 +73,79 @@ parsers_map: dict[Any, Callable[[Any], Any]] = {
     timedelta: _parse_timedelta,
 }
 
+custom_parsers_map: dict[str, Callable[[str], Any]] = {}
+
+
+def register_path_parameter_type(name: str, parser: Callable[[str], Any], type_: type = str) -> None:
+    """Register a custom path parameter type, e.g. to only match path segments that are valid ISBNs.
+
+    The parser is invoked with the matching path segment while routing a request. If it raises a ``ValueError``, the
+    segment is not considered a match and a ``404 Not Found`` response is returned. Otherwise, its return value is
+    passed to the route handler as the value of the path parameter.
+
+    Args:
+        name: The name of the type, used in path templates as ``{param_name:name}``.
+        parser: A callable receiving the path segment and returning the parsed value.
+        type_: The type of the value returned by ``parser``.
+
+    Raises:
+        ImproperlyConfiguredException: If a path parameter type with the given name is already registered.
+
+    Returns:
+        None
+    """
+    if name in param_type_map:
+        raise ImproperlyConfiguredException(f"Path parameter type {name!r} is already registered")
+    param_type_map[name] = type_
+    custom_parsers_map[name] = parser
+
+
+def _get_single_parameter_declaration(component: str) -> str | None:
+    """Get the declaration of the path parameter making up a whole path component, if any.
+
+    The constraint of a parameter may contain braces, but its name and type may not, which distinguishes
+    ``{sku:str:[A-Z]{3}}`` from ``{name:str}.{ext:str}``.
+    """
+    if (param_match := param_match_regex.fullmatch(component)) and "}" not in ":".join(
+        param_match.group(1).split(":", 2)[:2]
+    ):
+        return param_match.group(1)
+    return None
+
+
+def _describe_position(path: str, index: int) -> str:
+    """Describe a position in a path template for error messages, as the byte offset of the UTF-8 encoded path."""
+    return f"in path: '{path}' at byte offset {len(path[:index].encode())}"
+
+
+def _validate_braces(component: str, path: str, offset: int) -> None:
+    """Validate that the braces of a path component are balanced.
+
+    Braces escaped with a backslash, e.g. in the constraint of a path parameter, are ignored.
+
+    Raises:
+        ImproperlyConfiguredException: If a brace is not opened or not closed.
+    """
+    open_braces: list[int] = []
+    escaped = False
+    for index, char in enumerate(component):
+        if escaped:
+            escaped = False
+        elif char == "\\":
+            escaped = True
+        elif char == "{":
+            open_braces.append(index)
+        elif char == "}":
+            if not open_braces:
+                raise ImproperlyConfiguredException(
+                    f"Unbalanced closing brace {_describe_position(path, offset + index)}"
+                )
+            open_braces.pop()
+    if open_braces:
+        raise ImproperlyConfiguredException(
+            f"Unbalanced opening brace {_describe_position(path, offset + open_braces[0])}"
+        )
+
 
 class BaseRoute(ABC):
     """Base Route class used by Litestar.
@@ -103,7 +182,11 @@ class BaseRoute(ABC):
         """
         self.path, self.path_format, self.path_components = self._parse_path(path)
         self.path_parameters: tuple[PathParameterDefinition, ...] = tuple(
-            component for component in self.path_components if isinstance(component, PathParameterDefinition)
+            chain.from_iterable(
+                component.parameters if isinstance(component, PathSegmentPattern) else (component,)
+                for component in self.path_components
+                if not isinstance(component, str)
+            )
         )
         self.handler_names = handler_names
         self.scope_type = scope_type
@@ -141,53 +224,179 @@ class BaseRoute(ABC):
         )
 
     @staticmethod
-    def _validate_path_parameter(param: str, path: str) -> None:
+    def _validate_path_parameter(param: str, path: str, offset: int) -> None:
         """Validate that a path parameter adheres to the required format and datatypes.
 
+        Args:
+            param: The declaration of the path parameter.
+            path: The path the parameter is declared in.
+            offset: The index of the opening brace of the placeholder in ``path``.
+
         Raises:
             ImproperlyConfiguredException: If the parameter has an invalid format.
         """
-        if len(param.split(":")) != 2:
+        if len(param.split(":", 2)) < 2:
             raise ImproperlyConfiguredException(
-                f"Path parameters should be declared with a type using the following pattern: '{{parameter_name:type}}', e.g. '/my-path/{{my_param:int}}' in path: '{path}'"
+                f"Path parameters should be declared with a type using the following pattern: '{{parameter_name:type}}', e.g. '/my-path/{{my_param:int}}' {_describe_position(path, offset)}"
             )
-        param_name, param_type = (p.strip() for p in param.split(":"))
+        param_name, param_type = (p.strip() for p in param.split(":", 2)[:2])
         if not param_name:
-            raise ImproperlyConfiguredException("Path parameter names should be of length greater than zero")
+            raise ImproperlyConfiguredException(
+                f"Path parameter names should be of length greater than zero {_describe_position(path, offset)}"
+            )
         if param_type not in param_type_map:
             raise ImproperlyConfiguredException(
-                f"Path parameters should be declared with an allowed type, i.e. one of {', '.join(param_type_map.keys())} in path: '{path}'"
+                f"Path parameters should be declared with an allowed type, i.e. one of {', '.join(param_type_map.keys())}, but '{param_name}' is declared with type '{param_type}' {_describe_position(path, offset)}"
             )
 
     @classmethod
-    def _parse_path(cls, path: str) -> tuple[str, str, list[str | PathParameterDefinition]]:
+    def _parse_path_parameter(cls, param: str, path: str, offset: int) -> PathParameterDefinition:
+        """Parse the declaration of a path parameter, i.e. the text between the braces of a placeholder.
+
+        Args:
+            param: The declaration of the path parameter.
+            path: The path the parameter is declared in.
+            offset: The index of the opening brace of the placeholder in ``path``.
+
+        Raises:
+            ImproperlyConfiguredException: If the declaration is invalid.
+
+        Returns:
+            A path parameter definition.
+        """
+        cls._validate_path_parameter(param, path, offset)
+        param_name, param_type, *constraint = param.split(":", 2)
+        param_name, param_type = param_name.strip(), param_type.strip()
+        type_class = param_type_map[param_type]
+        parser = custom_parsers_map.get(param_type) or (
+            parsers_map[type_class] if type_class not in {str, Path} else None
+        )
+        try:
+            pattern = re.compile(constraint[0]) if constraint else None
+        except re.error as e:
+            raise ImproperlyConfiguredException(
+                f"Invalid constraint for path parameter '{param_name}' {_describe_position(path, offset)}: {e}"
+            ) from e
+        return PathParameterDefinition(name=param_name, type=type_class, full=param, parser=parser, pattern=pattern)
+
+    @classmethod
+    def _parse_segment_pattern(cls, component: str, path: str, offset: int) -> PathSegmentPattern:
+        """Parse a path component mixing literal text and placeholders, e.g. ``{name:str}.{ext:str}``.
+
+        Each placeholder matches as much of the component as possible, from left to right, while still allowing the
+        remainder of the component to match.
+
+        Args:
+            component: The path component.
+            path: The path the component is part of.
+            offset: The index of the component in ``path``.
+
+        Raises:
+            ImproperlyConfiguredException: If a placeholder is invalid or of type ``path``.
+
+        Returns:
+            A path segment pattern.
+        """
+        parameters: list[PathParameterDefinition] = []
+        literals: list[str] = []
+        regex = ""
+        position = 0
+        for param_match in segment_param_match_regex.finditer(component):
+            parameter = cls._parse_path_parameter(param_match.group(1), path, offset + param_match.start())
+            if parameter.type is Path:
+                raise ImproperlyConfiguredException(
+                    f"Path parameter '{parameter.name}' of type 'path' must be a whole path segment "
+                    f"{_describe_position(path, offset + param_match.start())}"
+                )
+            literal = component[position : param_match.start()]
+            parameters.append(parameter)
+            literals.append(literal)
+            regex += f"{re.escape(literal)}(.+)"
+            position = param_match.end()
+
+        literals.append(component[position:])
+        regex += re.escape(component[position:])
+        return PathSegmentPattern(
+            template=component, pattern=re.compile(regex), parameters=tuple(parameters), literals=tuple(literals)
+        )
+
+    @staticmethod
+    def _add_parameter_name(
+        parameter: PathParameterDefinition, parameter_names: set[str], path: str, offset: int
+    ) -> None:
+        """Record the name of a path parameter, ensuring that it is not declared more than once in a path.
+
+        Raises:
+            ImproperlyConfiguredException: If a parameter of the same name has already been declared.
+        """
+        if parameter.name in parameter_names:
+            raise ImproperlyConfiguredException(
+                f"Duplicate path parameter '{parameter.name}' {_describe_position(path, offset)}"
+            )
+        parameter_names.add(parameter.name)
+
+    @classmethod
+    def _parse_path(cls, path: str) -> tuple[str, str, list[str | PathParameterDefinition | PathSegmentPattern]]:
         """Normalize and parse a path.
 
         Splits the path into a list of components, parsing any that are path parameters. Also builds the OpenAPI
         compatible path, which does not include the type of the path parameters.
 
+        A path parameter may be constrained by a regular expression following its type, e.g.
+        ``{sku:str:^[A-Z]{3}-\\d+$}``. The expression is compiled here, and must match the whole path segment for the
+        route to match a request.
+
+        Components mixing literal text and placeholders, e.g. ``{name:str}.{ext:str}``, are parsed into a
+        :class:`PathSegmentPattern <litestar.types.internal_types.PathSegmentPattern>`.
+
+        Percent-encoded characters are decoded, since the ``path`` of an ASGI scope, which is matched against the
+        parsed path, is decoded as well. Encoded slashes are rejected, since decoding them would split a path segment.
+
+        The normalized path and its literal components are interned, since they are used as the keys of the routing
+        trie, and large route tables repeat the same components, e.g. ``api`` or ``users``, many times.
+
+        Errors in the declaration of path parameters, e.g. unbalanced braces, empty names, unknown types or names
+        declared more than once, are reported together with the path and the byte offset of the offending placeholder.
+
+        Raises:
+            ImproperlyConfiguredException: If the path contains NUL or other ASCII control characters or encoded
+                slashes, if a path parameter is declared incorrectly, or if the constraint of a path parameter is not
+                a valid regular expression.
+
         Returns:
             A 3-tuple of the normalized path, the OpenAPI formatted path, and the list of parsed components.
         """
-        path = normalize_path(path)
-
-        parsed_components: list[str | PathParameterDefinition] = []
+        if "%2f" in path.lower():
+            raise ImproperlyConfiguredException(f"Path {path!r} must not contain encoded slashes")
+        path = unquote(path)
+        if control_characters_pattern.search(path):
+            raise ImproperlyConfiguredException(f"Path {path!r} must not contain NUL or other control characters")
+        path = sys.intern(normalize_route_path(path))
+
+        parsed_components: list[str | PathParameterDefinition | PathSegmentPattern] = []
         path_format_components = []
 
-        components = [component for component in path.split("/") if component]
-        for component in components:
-            if param_match := param_match_regex.fullmatch(component):
-                param = param_match.group(1)
-                cls._validate_path_parameter(param, path)
-                param_name, param_type = (p.strip() for p in param.split(":"))
-                type_class = param_type_map[param_type]
-                parser = parsers_map[type_class] if type_class not in {str, Path} else None
-                parsed_components.append(
-                    PathParameterDefinition(name=param_name, type=type_class, full=param, parser=parser)
+        parameter_names: set[str] = set()
+        for component_match in path_component_regex.finditer(path):
+            component, offset = component_match.group(), component_match.start()
+            _validate_braces(component, path, offset)
+            if param := _get_single_parameter_declaration(component):
+                parameter = cls._parse_path_parameter(param, path, offset)
+                cls._add_parameter_name(parameter, parameter_names, path, offset)
+                parsed_components.append(parameter)
+                path_format_components.append("{" + parameter.name + "}")
+            elif segment_param_match_regex.search(component):
+                segment_pattern = cls._parse_segment_pattern(component, path, offset)
+                for parameter, param_match in zip(
+                    segment_pattern.parameters, segment_param_match_regex.finditer(component)
+                ):
+                    cls._add_parameter_name(parameter, parameter_names, path, offset + param_match.start())
+                parsed_components.append(segment_pattern)
+                path_format_components.append(
+                    segment_pattern.render("{" + parameter.name + "}" for parameter in segment_pattern.parameters)
                 )
-                path_format_components.append("{" + param_name + "}")
             else:
-                parsed_components.append(component)
+                parsed_components.append(sys.intern(component))
                 path_format_components.append(component)
 
         path_format = join_paths(path_format_components)
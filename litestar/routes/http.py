@@ -5,16 +5,19 @@ from typing import TYPE_CHECKING, Any, cast
 
 from msgspec.msgpack import decode as _decode_msgpack_plain
 
-from litestar.constants import DEFAULT_ALLOWED_CORS_HEADERS
+from litestar.constants import WILDCARD_HTTP_METHOD
 from litestar.datastructures.headers import Headers
 from litestar.datastructures.upload_file import UploadFile
 from litestar.enums import HttpMethod, MediaType, ScopeType
 from litestar.exceptions import ClientException, ImproperlyConfiguredException, SerializationException
 from litestar.handlers.http_handlers import HTTPRouteHandler
+from litestar.middleware.cors import validate_preflight_request
 from litestar.response import Response
 from litestar.routes.base import BaseRoute
 from litestar.status_codes import HTTP_204_NO_CONTENT, HTTP_400_BAD_REQUEST
 from litestar.types.empty import Empty
+from litestar.utils.empty import value_or_default
+from litestar.utils.helpers import get_enum_string_value
 from litestar.utils.scope.state import ScopeState
 
 if TYPE_CHECKING:
@@ -28,8 +31,12 @@ class HTTPRoute(BaseRoute):
     """An HTTP route, capable of handling multiple ``HTTPRouteHandler``\\ s."""  # noqa: D301
 
     __slots__ = (
+        "host_handler_map",
+        "media_type_handler_map",
+        "options_handler",
         "route_handler_map",
         "route_handlers",
+        "version_handler_map",
     )
 
     def __init__(
@@ -45,14 +52,19 @@ class HTTPRoute(BaseRoute):
             route_handlers: A list of :class:`~.handlers.HTTPRouteHandler`.
         """
         methods = list(chain.from_iterable([route_handler.http_methods for route_handler in route_handlers]))
-        if "OPTIONS" not in methods:
+        self.options_handler: HTTPRouteHandler | None = None
+        # a wildcard handler handles OPTIONS requests as well
+        if "OPTIONS" not in methods and WILDCARD_HTTP_METHOD not in methods:
             methods.append("OPTIONS")
-            options_handler = self.create_options_handler(path)
-            options_handler.owner = route_handlers[0].owner
-            route_handlers.append(options_handler)
+            self.options_handler = self.create_options_handler(path)
+            self.options_handler.owner = route_handlers[0].owner
+            route_handlers.append(self.options_handler)
 
         self.route_handlers = route_handlers
         self.route_handler_map: dict[Method, tuple[HTTPRouteHandler, KwargsModel]] = {}
+        self.media_type_handler_map: dict[Method, dict[str, tuple[HTTPRouteHandler, KwargsModel]]] = {}
+        self.host_handler_map: dict[Method, dict[str, tuple[HTTPRouteHandler, KwargsModel]]] = {}
+        self.version_handler_map: dict[Method, dict[str, tuple[HTTPRouteHandler, KwargsModel]]] = {}
 
         super().__init__(
             methods=methods,
@@ -73,7 +85,30 @@ class HTTPRoute(BaseRoute):
         Returns:
             None
         """
-        route_handler, parameter_model = self.route_handler_map[scope["method"]]
+        method = scope["method"]
+        if method not in self.route_handler_map:
+            # without a wildcard handler, a HEAD request has been routed to the GET handler by the router
+            method = (
+                "GET"
+                if method == "HEAD" and WILDCARD_HTTP_METHOD not in self.route_handler_map
+                else WILDCARD_HTTP_METHOD  # type: ignore[assignment]
+            )
+        route_handler, parameter_model = self.route_handler_map[method]  # type: ignore[index]
+        if (host_handlers := self.host_handler_map.get(method)) and (  # type: ignore[call-overload]
+            host := scope["route_handler"].opt.get("host")  # type: ignore[attr-defined]
+        ):
+            # the handler has been selected according to the 'Host' header by the router
+            route_handler, parameter_model = host_handlers[host.lower()]
+        elif (version_handlers := self.version_handler_map.get(method)) and (  # type: ignore[call-overload]
+            version := scope["route_handler"].opt.get("version")  # type: ignore[attr-defined]
+        ):
+            # the handler has been selected according to the requested API version by the router
+            route_handler, parameter_model = version_handlers[version]
+        elif media_type_handlers := self.media_type_handler_map.get(method):  # type: ignore[call-overload]
+            # the handler has been selected according to the 'Accept' header by the router
+            route_handler, parameter_model = media_type_handlers[
+                get_enum_string_value(scope["route_handler"].media_type)  # type: ignore[attr-defined]
+            ]
         request: Request[Any, Any, Any] = route_handler.resolve_request_class()(scope=scope, receive=receive, send=send)
 
         if route_handler.resolve_guards():
@@ -94,16 +129,61 @@ class HTTPRoute(BaseRoute):
     def create_handler_map(self) -> None:
         """Parse the ``router_handlers`` of this route and return a mapping of
         http- methods and route handlers.
+
+        Several handlers may handle the same method if they all explicitly declare different media types, e.g. to
+        version an API by vendor media types such as ``application/vnd.myapi.v2+json``. The router selects one of them
+        according to the ``Accept`` header of a request, falling back to the first registered handler.
+
+        Handlers declaring a ``host`` in their ``opt`` only serve requests for that host, and are selected by the
+        router according to the ``Host`` header of a request. Handlers without a ``host`` serve all other hosts.
+        Likewise, handlers declaring a ``version`` in their ``opt`` only serve requests for that API version.
         """
+        dispatched_handlers: list[tuple[str, HTTPRouteHandler]] = []
         for route_handler in self.route_handlers:
+            if route_handler.opt.get("host") and route_handler.opt.get("version"):
+                raise ImproperlyConfiguredException(
+                    f"Handler {route_handler} for path {self.path!r} cannot declare both a host and a version"
+                )
+            if dispatch_key := next((key for key in ("host", "version") if route_handler.opt.get(key)), None):
+                dispatched_handlers.append((dispatch_key, route_handler))
+                continue
             kwargs_model = self.create_handler_kwargs_model(route_handler=route_handler)
+            media_type = get_enum_string_value(route_handler.media_type)
             for http_method in route_handler.http_methods:
-                if self.route_handler_map.get(http_method):
-                    raise ImproperlyConfiguredException(
-                        f"Handler already registered for path {self.path!r} and http method {http_method}"
+                if existing := self.route_handler_map.get(http_method):
+                    if not (existing[0].has_declared_media_type and route_handler.has_declared_media_type):
+                        raise ImproperlyConfiguredException(
+                            f"Handler already registered for path {self.path!r} and http method {http_method}"
+                        )
+                    media_type_handlers = self.media_type_handler_map.setdefault(
+                        http_method, {get_enum_string_value(existing[0].media_type): existing}
                     )
+                    if media_type in media_type_handlers:
+                        raise ImproperlyConfiguredException(
+                            f"Handler already registered for path {self.path!r} and http method {http_method}"
+                        )
+                    media_type_handlers[media_type] = (route_handler, kwargs_model)
+                    continue
                 self.route_handler_map[http_method] = (route_handler, kwargs_model)
 
+        for dispatch_key, route_handler in dispatched_handlers:
+            kwargs_model = self.create_handler_kwargs_model(route_handler=route_handler)
+            value = route_handler.opt[dispatch_key]
+            if dispatch_key == "host":
+                handler_map, value = self.host_handler_map, value.lower()
+            else:
+                handler_map = self.version_handler_map
+            for http_method in route_handler.http_methods:
+                handlers = handler_map.setdefault(http_method, {})
+                if value in handlers:
+                    raise ImproperlyConfiguredException(
+                        f"Handler already registered for path {self.path!r}, http method {http_method} and "
+                        f"{dispatch_key} {value!r}"
+                    )
+                handlers[value] = (route_handler, kwargs_model)
+                # keeps the method routable if no handler serves all hosts or versions
+                self.route_handler_map.setdefault(http_method, (route_handler, kwargs_model))
+
     async def _get_response_for_request(
         self,
         scope: Scope,
@@ -221,7 +301,9 @@ class HTTPRoute(BaseRoute):
         """
 
         cache_config = request.app.response_cache_config
-        cache_key = (route_handler.cache_key_builder or cache_config.key_builder)(request)
+        cache_key = value_or_default(ScopeState.from_scope(request.scope).cache_key, None) or (
+            route_handler.cache_key_builder or cache_config.key_builder
+        )(request)
         store = cache_config.get_store_from_app(request.app)
 
         if not (cached_response_data := await store.get(key=cache_key)):
@@ -260,37 +342,9 @@ class HTTPRoute(BaseRoute):
             origin = request_headers.get("origin")
 
             if cors_config and origin:
-                pre_flight_method = request_headers.get("Access-Control-Request-Method")
-                failures = []
-
-                if not cors_config.is_allow_all_methods and (
-                    pre_flight_method and pre_flight_method not in cors_config.allow_methods
-                ):
-                    failures.append("method")
-
-                response_headers = cors_config.preflight_headers.copy()
-
-                if not cors_config.is_origin_allowed(origin):
-                    failures.append("Origin")
-                elif response_headers.get("Access-Control-Allow-Origin") != "*":
-                    response_headers["Access-Control-Allow-Origin"] = origin
-
-                pre_flight_requested_headers = [
-                    header.strip()
-                    for header in request_headers.get("Access-Control-Request-Headers", "").split(",")
-                    if header.strip()
-                ]
-
-                if pre_flight_requested_headers:
-                    if cors_config.is_allow_all_headers:
-                        response_headers["Access-Control-Allow-Headers"] = ", ".join(
-                            sorted(set(pre_flight_requested_headers) | DEFAULT_ALLOWED_CORS_HEADERS)  # pyright: ignore
-                        )
-                    elif any(
-                        header.lower() not in cors_config.allow_headers for header in pre_flight_requested_headers
-                    ):
-                        failures.append("headers")
-
+                failures, response_headers = validate_preflight_request(
+                    config=cors_config, request_headers=request_headers, origin=origin
+                )
                 return (
                     Response(
                         content=f"Disallowed CORS {', '.join(failures)}",
@@ -1,28 +1,116 @@
 from __future__ import annotations
 
+from datetime import timezone
+from email.utils import formatdate, parsedate_to_datetime
+from fnmatch import fnmatchcase
+from html import escape
 from os.path import commonpath
 from pathlib import Path
-from typing import TYPE_CHECKING, Literal, Sequence
+from typing import TYPE_CHECKING, Any, Literal, Mapping, Sequence
+from urllib.parse import quote
 
-from litestar.enums import ScopeType
-from litestar.exceptions import MethodNotAllowedException, NotFoundException
+from anyio import Path as AsyncPath
+
+from litestar.datastructures.headers import Accept, Headers
+from litestar.enums import MediaType, ScopeType
+from litestar.exceptions import ImproperlyConfiguredException, MethodNotAllowedException, NotFoundException
 from litestar.file_system import FileSystemAdapter
-from litestar.response.file import ASGIFileResponse
-from litestar.status_codes import HTTP_404_NOT_FOUND
+from litestar.response.base import ASGIResponse
+from litestar.response.file import ASGIFileResponse, create_etag_for_file
+from litestar.serialization import encode_json
+from litestar.status_codes import HTTP_304_NOT_MODIFIED, HTTP_404_NOT_FOUND
 
-__all__ = ("StaticFiles",)
+__all__ = ("StaticFiles", "is_not_modified")
 
 
 if TYPE_CHECKING:
-    from litestar.types import Receive, Scope, Send
+    from litestar.datastructures import CacheControlHeader
+    from litestar.types import Receive, Scope, Send, SymlinkPolicy
     from litestar.types.composite_types import PathType
     from litestar.types.file_types import FileInfo, FileSystemProtocol
 
 
+# the encodings of precompressed variants of files, in the order of preference, with the suffixes of their file names
+_precompressed_encodings = (("br", ".br"), ("gzip", ".gz"))
+
+_directory_listing_template = """<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Index of {path}</title></head>
+<body>
+<h1>Index of {path}</h1>
+<ul>
+{entries}
+</ul>
+</body>
+</html>
+"""
+
+
+def _strip_weak_prefix(etag: str) -> str:
+    return etag[2:] if etag.startswith("W/") else etag
+
+
+def is_not_modified(request_headers: Mapping[str, str], etag: str, modified_time: float) -> bool:
+    """Evaluate the conditional headers of a ``GET`` or ``HEAD`` request for a file.
+
+    As specified by :rfc:`9110#section-13.2.2`, ``If-None-Match`` takes precedence over ``If-Modified-Since``, and
+    entity tags are compared weakly.
+
+    Args:
+        request_headers: The headers of the request.
+        etag: The entity tag of the file.
+        modified_time: The modification time of the file, as a timestamp.
+
+    Returns:
+        A boolean indicating whether the file has not been modified, so that a ``304`` response can be sent.
+    """
+    if (if_none_match := request_headers.get("if-none-match")) is not None:
+        if if_none_match.strip() == "*":
+            return True
+        return _strip_weak_prefix(etag) in {_strip_weak_prefix(tag.strip()) for tag in if_none_match.split(",")}
+
+    if (if_modified_since := request_headers.get("if-modified-since")) is not None:
+        try:
+            since = parsedate_to_datetime(if_modified_since)
+        except (TypeError, ValueError):
+            # invalid dates are ignored
+            return False
+        if since.tzinfo is None:
+            since = since.replace(tzinfo=timezone.utc)
+        # HTTP dates have a resolution of one second
+        return int(modified_time) <= since.timestamp()
+
+    return False
+
+
+def _parse_accept_encoding(accept_encoding: str) -> dict[str, float]:
+    qualities: dict[str, float] = {}
+    for item in accept_encoding.split(","):
+        encoding, _, parameters = item.partition(";")
+        parameters = parameters.strip()
+        try:
+            quality = float(parameters[2:]) if parameters.startswith("q=") else 1.0
+        except ValueError:
+            continue
+        if encoding := encoding.strip().lower():
+            qualities[encoding] = quality
+    return qualities
+
+
 class StaticFiles:
     """ASGI App that handles file sending."""
 
-    __slots__ = ("is_html_mode", "directories", "adapter", "send_as_attachment", "headers")
+    __slots__ = (
+        "is_html_mode",
+        "directories",
+        "adapter",
+        "send_as_attachment",
+        "headers",
+        "symlink_policy",
+        "precompressed",
+        "directory_listing",
+        "cache_control_rules",
+    )
 
     def __init__(
         self,
@@ -32,6 +120,10 @@ class StaticFiles:
         send_as_attachment: bool = False,
         resolve_symlinks: bool = True,
         headers: dict[str, str] | None = None,
+        symlink_policy: SymlinkPolicy = "follow",
+        precompressed: bool = False,
+        directory_listing: bool = False,
+        cache_control_rules: Mapping[str, CacheControlHeader] | None = None,
     ) -> None:
         """Initialize the Application.
 
@@ -43,12 +135,37 @@ class StaticFiles:
              ``attachment`` or ``inline``
             resolve_symlinks: Resolve symlinks to the directories
             headers: Headers that will be sent with every response.
+            symlink_policy: How to handle symbolic links within the directories. With ``"follow"``, they are followed
+                wherever they point to. With ``"within_directory"``, only links pointing to a location within the
+                directory are followed. With ``"deny"``, files are not served if their path within the directory
+                contains a link. The links are resolved on the local file system.
+            precompressed: Serve precompressed variants of files, e.g. ``app.js.br`` or ``app.js.gz`` next to
+                ``app.js``, to requests accepting their encoding.
+            directory_listing: Respond to requests for directories with a listing of their entries, as HTML or as
+                JSON depending on the ``Accept`` header of the request. Requires the file system to provide an ``ls``
+                method, like the file systems of ``fsspec``.
+            cache_control_rules: A mapping of glob patterns, e.g. ``"*.js"`` or ``"assets/*"``, to the
+                ``cache-control`` header of the files whose path relative to the directories matches them. The first
+                matching pattern takes precedence over a ``cache-control`` header in ``headers``.
+
+        Raises:
+            ImproperlyConfiguredException: If ``directory_listing`` is enabled and the file system cannot list
+                directories.
         """
+        if directory_listing and not callable(getattr(file_system, "ls", None)):
+            raise ImproperlyConfiguredException("file_system must provide an 'ls' method to list directories")
+
         self.adapter = FileSystemAdapter(file_system)
         self.directories = tuple(Path(p).resolve() if resolve_symlinks else Path(p) for p in directories)
         self.is_html_mode = is_html_mode
         self.send_as_attachment = send_as_attachment
         self.headers = headers
+        self.symlink_policy = symlink_policy
+        self.precompressed = precompressed
+        self.directory_listing = directory_listing
+        self.cache_control_rules = tuple(
+            (pattern, header.to_header()) for pattern, header in (cache_control_rules or {}).items()
+        )
 
     async def get_fs_info(
         self, directories: Sequence[PathType], file_path: PathType
@@ -67,12 +184,151 @@ class StaticFiles:
             try:
                 joined_path = Path(directory, file_path)
                 file_info = await self.adapter.info(joined_path)
-                if file_info and commonpath([str(directory), file_info["name"], joined_path]) == str(directory):
+                if (
+                    file_info
+                    and commonpath([str(directory), file_info["name"], joined_path]) == str(directory)
+                    and await self.is_allowed_by_symlink_policy(directory=directory, joined_path=joined_path)
+                ):
                     return joined_path, file_info
             except FileNotFoundError:
                 continue
         return None, None
 
+    async def is_allowed_by_symlink_policy(self, directory: PathType, joined_path: Path) -> bool:
+        """Check whether a file within a directory may be served according to the symlink policy.
+
+        Args:
+            directory: The directory the file is served from.
+            joined_path: The path of the file, joined to the directory.
+
+        Returns:
+            A boolean indicating whether the file may be served.
+        """
+        if self.symlink_policy == "follow":
+            return True
+
+        resolved_directory = str(await AsyncPath(directory).resolve())
+        resolved_path = str(await AsyncPath(joined_path).resolve())
+        if self.symlink_policy == "deny":
+            return resolved_path == str(Path(resolved_directory, joined_path.relative_to(directory)))
+        return commonpath([resolved_directory, resolved_path]) == resolved_directory
+
+    def get_cache_control(self, path: str) -> str | None:
+        """Get the value of the ``cache-control`` header of a file according to the ``cache_control_rules``.
+
+        Args:
+            path: The path of the file, relative to the directories.
+
+        Returns:
+            The header value of the first matching rule, or ``None`` if no rule matches.
+        """
+        return next((value for pattern, value in self.cache_control_rules if fnmatchcase(path, pattern)), None)
+
+    def _get_directory(self, path: Path) -> Path | None:
+        return next((d for d in self.directories if commonpath([str(d), str(path)]) == str(d)), None)
+
+    async def get_precompressed_variant(
+        self, file_path: Path, accept_encoding: str
+    ) -> tuple[Path, FileInfo, str] | tuple[None, None, None]:
+        """Find a precompressed variant of a file in an encoding accepted by a request.
+
+        Brotli is preferred over gzip if both are accepted.
+
+        Args:
+            file_path: The path of the file.
+            accept_encoding: The ``Accept-Encoding`` header of the request.
+
+        Returns:
+            A tuple of the path and file info of the variant and its encoding, or a tuple of ``None`` if no variant is
+            found.
+        """
+        qualities = _parse_accept_encoding(accept_encoding)
+        directory = self._get_directory(file_path)
+        if directory is None:
+            return None, None, None
+
+        for encoding, suffix in _precompressed_encodings:
+            # an encoding not listed is accepted with the quality of the wildcard, if any
+            if qualities.get(encoding, qualities.get("*", 0)) <= 0:
+                continue
+            variant_path = file_path.with_name(f"{file_path.name}{suffix}")
+            try:
+                file_info = await self.adapter.info(variant_path)
+            except FileNotFoundError:
+                continue
+            if file_info["type"] == "file" and await self.is_allowed_by_symlink_policy(
+                directory=directory, joined_path=variant_path
+            ):
+                return variant_path, file_info, encoding
+        return None, None, None
+
+    async def create_directory_listing(
+        self,
+        directory_path: Path,
+        request_path: str,
+        is_head_response: bool,
+        request_headers: Mapping[str, str] | None = None,
+    ) -> ASGIResponse:
+        """Create a response listing the entries of a directory.
+
+        Entries that cannot be served according to the symlink policy are omitted.
+
+        Args:
+            directory_path: The path of the directory.
+            request_path: The full path of the request, which the links to the entries are relative to.
+            is_head_response: Whether the response is sent to a ``HEAD`` request.
+            request_headers: The headers of the request, whose ``Accept`` header selects between an HTML and a JSON
+                listing.
+
+        Returns:
+            The response.
+        """
+        base_directory = self._get_directory(directory_path)
+        entries: list[dict[str, Any]] = []
+        for file_info in await self.adapter.ls(directory_path):
+            entry_path = Path(file_info["name"])
+            if base_directory is None or not await self.is_allowed_by_symlink_policy(
+                directory=base_directory, joined_path=entry_path
+            ):
+                continue
+            entries.append(
+                {
+                    "name": entry_path.name,
+                    "type": file_info["type"],
+                    "size": file_info.get("size"),
+                    "mtime": file_info.get("mtime"),
+                }
+            )
+        entries.sort(key=lambda entry: (entry["type"] != "directory", entry["name"]))
+
+        headers = {**(self.headers or {}), "vary": "Accept"}
+        accept = Accept((request_headers or {}).get("accept", MediaType.HTML))
+        if accept.best_match([MediaType.HTML, MediaType.JSON], default=MediaType.HTML) == MediaType.JSON:
+            return ASGIResponse(
+                body=b"" if is_head_response else encode_json({"path": request_path, "entries": entries}),
+                media_type=MediaType.JSON,
+                headers=headers,
+                is_head_response=is_head_response,
+            )
+
+        base_path = request_path.rstrip("/")
+        links: list[tuple[str, str]] = []
+        if base_path and directory_path not in self.directories:
+            links.append((f"{base_path.rsplit('/', 1)[0]}/", "../"))
+        for entry in entries:
+            name = f"{entry['name']}/" if entry["type"] == "directory" else entry["name"]
+            links.append((f"{base_path}/{name}", name))
+        body = _directory_listing_template.format(
+            path=escape(request_path),
+            entries="\n".join(f'<li><a href="{escape(quote(href))}">{escape(name)}</a></li>' for href, name in links),
+        )
+        return ASGIResponse(
+            body=b"" if is_head_response else body.encode(),
+            media_type=MediaType.HTML,
+            headers=headers,
+            is_head_response=is_head_response,
+        )
+
     async def __call__(self, scope: Scope, receive: Receive, send: Send) -> None:
         """ASGI callable.
 
@@ -85,36 +341,103 @@ class StaticFiles:
             None
         """
         if scope["type"] != ScopeType.HTTP or scope["method"] not in {"GET", "HEAD"}:
-            raise MethodNotAllowedException()
+            raise MethodNotAllowedException(allowed_methods=("GET", "HEAD"))
 
-        res = await self.handle(path=scope["path"], is_head_response=scope["method"] == "HEAD")
+        res = await self.handle(
+            path=scope["path"],
+            is_head_response=scope["method"] == "HEAD",
+            request_headers=Headers.from_scope(scope),
+            request_path=scope.get("root_path", "") + scope["path"],
+        )
         await res(scope=scope, receive=receive, send=send)
 
-    async def handle(self, path: str, is_head_response: bool) -> ASGIFileResponse:
+    async def handle(
+        self,
+        path: str,
+        is_head_response: bool,
+        request_headers: Mapping[str, str] | None = None,
+        request_path: str | None = None,
+    ) -> ASGIResponse:
+        """Create the response serving a file.
+
+        Args:
+            path: The path of the file, relative to the directories.
+            is_head_response: Whether the response is sent to a ``HEAD`` request.
+            request_headers: The headers of the request. If given, its conditional headers are evaluated, and a
+                ``304`` response is returned if the file has not been modified.
+            request_path: The full path of the request, which the links of directory listings are relative to.
+                Defaults to ``path``.
+
+        Raises:
+            NotFoundException: If no file matches the path.
+
+        Returns:
+            The response.
+        """
         split_path = path.split("/")
         filename = split_path[-1]
         joined_path = Path(*split_path)
+        if ".." in joined_path.parts:
+            # the path of a request cannot escape the directories
+            raise NotFoundException(f"no file or directory match the path {joined_path} was found")
         resolved_path, fs_info = await self.get_fs_info(directories=self.directories, file_path=joined_path)
+        directory_path = resolved_path if fs_info and fs_info["type"] == "directory" else None
         content_disposition_type: Literal["inline", "attachment"] = (
             "attachment" if self.send_as_attachment else "inline"
         )
 
+        relative_path = joined_path
         if self.is_html_mode and fs_info and fs_info["type"] == "directory":
             filename = "index.html"
+            relative_path = joined_path / filename
             resolved_path, fs_info = await self.get_fs_info(
                 directories=self.directories,
                 file_path=Path(resolved_path or joined_path) / filename,
             )
 
         if fs_info and fs_info["type"] == "file":
+            file_path = resolved_path or joined_path
+            headers = self.headers
+            if cache_control := self.get_cache_control(relative_path.as_posix()):
+                headers = {**(self.headers or {}), "cache-control": cache_control}
+            if self.precompressed:
+                # the response depends on the accepted encodings, even if no variant is served
+                headers = {**(headers or {}), "vary": "Accept-Encoding"}
+                variant_path, variant_info, encoding = await self.get_precompressed_variant(
+                    file_path=file_path, accept_encoding=(request_headers or {}).get("accept-encoding", "")
+                )
+                if variant_path and variant_info and encoding:
+                    file_path, fs_info = variant_path, variant_info
+                    headers["content-encoding"] = encoding
+
+            if request_headers is not None:
+                etag = create_etag_for_file(path=file_path, modified_time=fs_info["mtime"], file_size=fs_info["size"])
+                if is_not_modified(request_headers, etag=etag, modified_time=fs_info["mtime"]):
+                    return ASGIResponse(
+                        status_code=HTTP_304_NOT_MODIFIED,
+                        headers={
+                            **(headers or {}),
+                            "etag": etag,
+                            "last-modified": formatdate(fs_info["mtime"], usegmt=True),
+                        },
+                    )
+
             return ASGIFileResponse(
-                file_path=resolved_path or joined_path,
+                file_path=file_path,
                 file_info=fs_info,
                 file_system=self.adapter.file_system,
                 filename=filename,
                 content_disposition_type=content_disposition_type,
                 is_head_response=is_head_response,
-                headers=self.headers,
+                headers=headers,
+            )
+
+        if self.directory_listing and directory_path is not None:
+            return await self.create_directory_listing(
+                directory_path=directory_path,
+                request_path=request_path if request_path is not None else path,
+                is_head_response=is_head_response,
+                request_headers=request_headers,
             )
 
         if self.is_html_mode:
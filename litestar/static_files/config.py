@@ -2,12 +2,13 @@ from __future__ import annotations
 
 from dataclasses import dataclass
 from pathlib import PurePath  # noqa: TCH003
-from typing import TYPE_CHECKING, Any, Sequence
+from typing import TYPE_CHECKING, Any, Mapping, Sequence
 
+from litestar.connection import Request  # noqa: TCH001
 from litestar.exceptions import ImproperlyConfiguredException
 from litestar.file_system import BaseLocalFileSystem
 from litestar.handlers import asgi, get, head
-from litestar.response.file import ASGIFileResponse  # noqa: TCH001
+from litestar.response.base import ASGIResponse  # noqa: TCH001
 from litestar.router import Router
 from litestar.static_files.base import StaticFiles
 from litestar.types import Empty
@@ -28,6 +29,7 @@ if TYPE_CHECKING:
         Guard,
         Middleware,
         PathType,
+        SymlinkPolicy,
     )
 
 
@@ -126,6 +128,10 @@ def create_static_files_router(
     tags: Sequence[str] | None = None,
     router_class: type[Router] = Router,
     resolve_symlinks: bool = True,
+    symlink_policy: SymlinkPolicy = "follow",
+    precompressed: bool = False,
+    directory_listing: bool = False,
+    cache_control_rules: Mapping[str, CacheControlHeader] | None = None,
 ) -> Router:
     """Create a router with handlers to serve static files.
 
@@ -154,6 +160,13 @@ def create_static_files_router(
         tags: ``tags`` passed to the router
         router_class: The class used to construct a router from
         resolve_symlinks: Resolve symlinks of ``directories``
+        symlink_policy: How to handle symlinks within ``directories``: follow them, only follow them if they point
+            within the directory, or deny serving files through them
+        precompressed: Serve precompressed variants of files, e.g. ``app.js.br`` or ``app.js.gz`` next to ``app.js``,
+            to requests accepting their encoding
+        directory_listing: Respond to requests for directories with a listing of their entries, as HTML or JSON
+        cache_control_rules: A mapping of glob patterns to the ``cache-control`` header of the files matching them,
+            taking precedence over ``cache_control``
     """
 
     if file_system is None:
@@ -173,23 +186,39 @@ def create_static_files_router(
         send_as_attachment=send_as_attachment,
         resolve_symlinks=resolve_symlinks,
         headers=headers,
+        symlink_policy=symlink_policy,
+        precompressed=precompressed,
+        directory_listing=directory_listing,
+        cache_control_rules=cache_control_rules,
     )
 
     @get("{file_path:path}", name=name)
-    async def get_handler(file_path: PurePath) -> ASGIFileResponse:
-        return await static_files.handle(path=file_path.as_posix(), is_head_response=False)
+    async def get_handler(file_path: PurePath, request: Request) -> ASGIResponse:
+        return await static_files.handle(
+            path=file_path.as_posix(),
+            is_head_response=False,
+            request_headers=request.headers,
+            request_path=request.url.path,
+        )
 
     @head("/{file_path:path}", name=f"{name}/head")
-    async def head_handler(file_path: PurePath) -> ASGIFileResponse:
-        return await static_files.handle(path=file_path.as_posix(), is_head_response=True)
+    async def head_handler(file_path: PurePath, request: Request) -> ASGIResponse:
+        return await static_files.handle(
+            path=file_path.as_posix(),
+            is_head_response=True,
+            request_headers=request.headers,
+            request_path=request.url.path,
+        )
 
     handlers = [get_handler, head_handler]
 
-    if html_mode:
+    if html_mode or directory_listing:
 
         @get("/", name=f"{name}/index")
-        async def index_handler() -> ASGIFileResponse:
-            return await static_files.handle(path="/", is_head_response=False)
+        async def index_handler(request: Request) -> ASGIResponse:
+            return await static_files.handle(
+                path="/", is_head_response=False, request_headers=request.headers, request_path=request.url.path
+            )
 
         handlers.append(index_handler)
 
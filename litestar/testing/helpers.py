@@ -19,6 +19,7 @@ if TYPE_CHECKING:
     from litestar.config.cors import CORSConfig
     from litestar.config.csrf import CSRFConfig
     from litestar.config.response_cache import ResponseCacheConfig
+    from litestar.config.versioning import VersioningConfig
     from litestar.datastructures import CacheControlHeader, ETag, State
     from litestar.dto import AbstractDTO
     from litestar.events import BaseEventEmitterBackend, EventListener
@@ -40,6 +41,7 @@ if TYPE_CHECKING:
         ControllerRouterHandler,
         Dependencies,
         EmptyType,
+        EncodedSlashHandling,
         ExceptionHandlersMap,
         Guard,
         LifespanHook,
@@ -110,6 +112,12 @@ def create_test_client(
     type_encoders: TypeEncodersMap | None = None,
     websocket_class: type[WebSocket] | None = None,
     experimental_features: list[ExperimentalFeatures] | None = None,
+    redirect_to_canonical_path: bool = False,
+    head_falls_back_to_get: bool = False,
+    versioning_config: VersioningConfig | None = None,
+    encoded_slash_handling: EncodedSlashHandling = "split",
+    normalize_unicode_paths: bool = False,
+    route_on_raw_path: bool = False,
 ) -> TestClient[Litestar]:
     """Create a Litestar app instance and initializes it.
 
@@ -234,6 +242,13 @@ def create_test_client(
         websocket_class: An optional subclass of :class:`WebSocket <.connection.WebSocket>` to use for websocket
             connections.
         experimental_features: An iterable of experimental features to enable
+        redirect_to_canonical_path: Redirect requests whose paths are not in their canonical form to the canonical path
+            with a ``308`` response.
+        head_falls_back_to_get: Serve ``HEAD`` requests to paths without a ``HEAD`` handler with their ``GET`` handler.
+        versioning_config: If set, enables API version-aware routing.
+        encoded_slash_handling: How to handle encoded slashes (``%2F``) in request paths.
+        normalize_unicode_paths: Apply Unicode normalization form C (NFC) to the paths of routes and requests.
+        route_on_raw_path: Route requests by the ``raw_path`` of the connection scope instead of the decoded ``path``.
 
 
     Returns:
@@ -293,6 +308,12 @@ def create_test_client(
         type_encoders=type_encoders,
         websocket_class=websocket_class,
         experimental_features=experimental_features,
+        redirect_to_canonical_path=redirect_to_canonical_path,
+        head_falls_back_to_get=head_falls_back_to_get,
+        versioning_config=versioning_config,
+        encoded_slash_handling=encoded_slash_handling,
+        normalize_unicode_paths=normalize_unicode_paths,
+        route_on_raw_path=route_on_raw_path,
     )
 
     return TestClient[Litestar](
@@ -365,6 +386,12 @@ def create_async_test_client(
     type_encoders: TypeEncodersMap | None = None,
     websocket_class: type[WebSocket] | None = None,
     experimental_features: list[ExperimentalFeatures] | None = None,
+    redirect_to_canonical_path: bool = False,
+    head_falls_back_to_get: bool = False,
+    versioning_config: VersioningConfig | None = None,
+    encoded_slash_handling: EncodedSlashHandling = "split",
+    normalize_unicode_paths: bool = False,
+    route_on_raw_path: bool = False,
 ) -> AsyncTestClient[Litestar]:
     """Create a Litestar app instance and initializes it.
 
@@ -489,6 +516,13 @@ def create_async_test_client(
         websocket_class: An optional subclass of :class:`WebSocket <.connection.WebSocket>` to use for websocket
             connections.
         experimental_features: An iterable of experimental features to enable
+        redirect_to_canonical_path: Redirect requests whose paths are not in their canonical form to the canonical path
+            with a ``308`` response.
+        head_falls_back_to_get: Serve ``HEAD`` requests to paths without a ``HEAD`` handler with their ``GET`` handler.
+        versioning_config: If set, enables API version-aware routing.
+        encoded_slash_handling: How to handle encoded slashes (``%2F``) in request paths.
+        normalize_unicode_paths: Apply Unicode normalization form C (NFC) to the paths of routes and requests.
+        route_on_raw_path: Route requests by the ``raw_path`` of the connection scope instead of the decoded ``path``.
 
     Returns:
         An instance of :class:`AsyncTestClient <litestar.testing.AsyncTestClient>` with a created app instance.
@@ -547,6 +581,12 @@ def create_async_test_client(
         type_encoders=type_encoders,
         websocket_class=websocket_class,
         experimental_features=experimental_features,
+        redirect_to_canonical_path=redirect_to_canonical_path,
+        head_falls_back_to_get=head_falls_back_to_get,
+        versioning_config=versioning_config,
+        encoded_slash_handling=encoded_slash_handling,
+        normalize_unicode_paths=normalize_unicode_paths,
+        route_on_raw_path=route_on_raw_path,
     )
 
     return AsyncTestClient[Litestar](
@@ -187,6 +187,7 @@ class RequestFactory:
             raw_path=path.encode("ascii"),
             route_handler=route_handler
             or _create_default_route_handler(http_method, self.handler_kwargs, app=self.app),
+            route_template=path,
             extensions={},
         )
 
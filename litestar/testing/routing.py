@@ -0,0 +1,177 @@
+from __future__ import annotations
+
+import string
+from random import Random
+from typing import TYPE_CHECKING, Any, NamedTuple
+from uuid import UUID
+
+__all__ = ("RoutingCase", "generate_cases", "generate_routes")
+
+
+if TYPE_CHECKING:
+    from litestar._asgi.route_spec import RouteSpec
+    from litestar.types import ASGIApp, Method, Receive, Scope, Send
+
+
+_segment_alphabet = string.ascii_lowercase + string.digits + "-_~"
+_resource_names = (
+    "accounts",
+    "articles",
+    "comments",
+    "events",
+    "files",
+    "groups",
+    "invoices",
+    "items",
+    "messages",
+    "orders",
+    "payments",
+    "products",
+    "projects",
+    "reports",
+    "settings",
+    "tags",
+    "tasks",
+    "teams",
+    "tokens",
+    "users",
+)
+_methods: tuple[Method, ...] = ("GET", "POST", "PUT", "PATCH", "DELETE")
+
+
+class RoutingCase(NamedTuple):
+    """A route template together with a request path it must match."""
+
+    template: str
+    """The path template of the route, e.g. ``/r0/items/{p1:int}``."""
+    path: str
+    """A request path rendered from ``template``."""
+    path_params: dict[str, Any]
+    """The path parameters routing ``path`` must produce."""
+
+
+def _random_segment(rng: Random) -> str:
+    return "".join(rng.choice(_segment_alphabet) for _ in range(rng.randint(1, 8)))
+
+
+def _render_parameter(rng: Random, param_type: str) -> tuple[str, Any]:
+    if param_type == "int":
+        value = rng.randint(-1000, 10**6)
+        return str(value), value
+    if param_type == "uuid":
+        uuid = UUID(int=rng.getrandbits(128), version=4)
+        return str(uuid), uuid
+    if param_type == "path":
+        rendered = "/".join(_random_segment(rng) for _ in range(rng.randint(1, 3)))
+        return rendered, f"/{rendered}"
+    rendered = _random_segment(rng)
+    return rendered, rendered
+
+
+def generate_cases(seed: int, n: int) -> list[RoutingCase]:
+    """Generate routing test cases deterministically.
+
+    Each case uses a distinct first path segment, so that all cases generated by a call can be registered on the same
+    application without conflicting. Re-running with the same ``seed`` reproduces the cases, e.g. to debug a failure
+    found by a property-based test.
+
+    Args:
+        seed: The seed of the random number generator.
+        n: The number of cases to generate.
+
+    Returns:
+        A list of routing cases.
+    """
+    rng = Random(seed)
+    cases: list[RoutingCase] = []
+    for i in range(n):
+        template_components = [f"r{i}"]
+        path_components = [f"r{i}"]
+        path_params: dict[str, Any] = {}
+        depth = rng.randint(0, 4)
+        for j in range(depth):
+            if rng.random() < 0.5:
+                segment = _random_segment(rng)
+                template_components.append(segment)
+                path_components.append(segment)
+                continue
+
+            param_type = rng.choice(("str", "int", "uuid", "path" if j == depth - 1 else "str"))
+            rendered, value = _render_parameter(rng, param_type)
+            template_components.append(f"{{p{j}:{param_type}}}")
+            path_components.append(rendered)
+            path_params[f"p{j}"] = value
+
+        cases.append(
+            RoutingCase(
+                template="/" + "/".join(template_components),
+                path="/" + "/".join(path_components),
+                path_params=path_params,
+            )
+        )
+    return cases
+
+
+async def _empty_response_app(scope: Scope, receive: Receive, send: Send) -> None:
+    if scope["type"] == "http":
+        await send({"type": "http.response.start", "status": 200, "headers": []})
+        await send({"type": "http.response.body", "body": b"", "more_body": False})
+
+
+def generate_routes(
+    seed: int,
+    count: int,
+    depth: int = 4,
+    params_ratio: float = 0.3,
+    handler: ASGIApp | None = None,
+) -> list[RouteSpec]:
+    """Generate a synthetic route table deterministically, e.g. for load tests and benchmarks.
+
+    Routes share prefixes drawn from a small vocabulary of resource names, like the routes of a real application. The
+    generated routes never conflict with each other, so they can be registered with
+    :meth:`ASGIRouter.add_routes <litestar._asgi.asgi_router.ASGIRouter.add_routes>`.
+
+    Args:
+        seed: The seed of the random number generator.
+        count: The number of routes to generate.
+        depth: The maximum number of path segments of a route.
+        params_ratio: The probability of a path segment being a path parameter.
+        handler: The ASGI app serving the routes. Defaults to an app sending an empty ``200`` response.
+
+    Raises:
+        ValueError: If ``count`` distinct routes cannot be generated with the given ``depth`` and ``params_ratio``.
+
+    Returns:
+        A list of route specs.
+    """
+    rng = Random(seed)
+    asgi_app = handler or _empty_response_app
+    # path parameters of a prefix must have the same name and type across routes, or the routes would conflict
+    prefix_parameters: dict[str, str] = {}
+    templates: set[str] = set()
+    route_specs: list[RouteSpec] = []
+
+    for _ in range(count * 100):
+        if len(route_specs) == count:
+            return route_specs
+
+        template = ""
+        for i in range(rng.randint(1, depth)):
+            if i and rng.random() < params_ratio:
+                template += prefix_parameters.setdefault(template, f"/{{p{i}:{rng.choice(('int', 'str', 'uuid'))}}}")
+            else:
+                template += f"/{rng.choice(_resource_names)}"
+
+        if template not in templates:
+            templates.add(template)
+            route_specs.append(
+                {
+                    "path": template,
+                    "handler": asgi_app,
+                    "methods": sorted(rng.sample(_methods, rng.randint(1, 2))),
+                }
+            )
+
+    if len(route_specs) < count:
+        raise ValueError(f"Cannot generate {count} distinct routes with a depth of {depth}")
+    return route_specs
@@ -73,7 +73,16 @@ from .composite_types import (
 )
 from .empty import Empty, EmptyType
 from .file_types import FileInfo, FileSystemProtocol
-from .helper_types import AnyIOBackend, MaybePartial, OptionalSequence, SSEData, StreamType, SyncOrAsyncUnion
+from .helper_types import (
+    AnyIOBackend,
+    EncodedSlashHandling,
+    MaybePartial,
+    OptionalSequence,
+    SSEData,
+    StreamType,
+    SymlinkPolicy,
+    SyncOrAsyncUnion,
+)
 from .internal_types import ControllerRouterHandler, ReservedKwargs, RouteHandlerMapItem, RouteHandlerType
 from .protocols import DataclassProtocol, Logger
 from .serialization import DataContainerType, LitestarEncodableType
@@ -98,6 +107,7 @@ __all__ = (
     "Dependencies",
     "Empty",
     "EmptyType",
+    "EncodedSlashHandling",
     "ExceptionHandler",
     "ExceptionHandlersMap",
     "FileInfo",
@@ -149,6 +159,7 @@ __all__ = (
     "Serializer",
     "StreamType",
     "SSEData",
+    "SymlinkPolicy",
     "SyncOrAsyncUnion",
     "TypeDecodersSequence",
     "TypeEncodersMap",
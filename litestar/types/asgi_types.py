@@ -136,6 +136,7 @@ class BaseScope(HeaderScope):
     raw_path: bytes
     root_path: str
     route_handler: RouteHandlerType
+    route_template: str
     scheme: str
     server: tuple[str, int | None] | None
     session: ScopeSession
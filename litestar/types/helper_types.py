@@ -25,7 +25,16 @@ if TYPE_CHECKING:
 
 T = TypeVar("T")
 
-__all__ = ("OptionalSequence", "SyncOrAsyncUnion", "AnyIOBackend", "StreamType", "MaybePartial", "SSEData")
+__all__ = (
+    "OptionalSequence",
+    "SyncOrAsyncUnion",
+    "AnyIOBackend",
+    "EncodedSlashHandling",
+    "StreamType",
+    "SymlinkPolicy",
+    "MaybePartial",
+    "SSEData",
+)
 
 OptionalSequence: TypeAlias = Optional[Sequence[T]]
 """Types 'T' as union of Sequence[T] and None."""
@@ -37,6 +46,12 @@ SyncOrAsyncUnion: TypeAlias = Union[T, Awaitable[T]]
 AnyIOBackend: TypeAlias = Literal["asyncio", "trio"]
 """Anyio backend names."""
 
+EncodedSlashHandling: TypeAlias = Literal["split", "reject", "decode", "pass_through"]
+"""Ways of handling encoded slashes (``%2F``) in request paths."""
+
+SymlinkPolicy: TypeAlias = Literal["follow", "within_directory", "deny"]
+"""Ways of handling symbolic links within the directories static files are served from."""
+
 StreamType: TypeAlias = Union[Iterable[T], Iterator[T], AsyncIterable[T], AsyncIterator[T]]
 """A stream type."""
 
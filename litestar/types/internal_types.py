@@ -1,6 +1,7 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any, Callable, Literal, NamedTuple
+from itertools import chain
+from typing import TYPE_CHECKING, Any, Callable, Iterable, Literal, NamedTuple, Pattern
 
 from litestar.utils.deprecation import warn_deprecation
 
@@ -8,6 +9,7 @@ __all__ = (
     "ControllerRouterHandler",
     "PathParameterDefinition",
     "PathParameterDefinition",
+    "PathSegmentPattern",
     "ReservedKwargs",
     "RouteHandlerMapItem",
     "RouteHandlerType",
@@ -43,6 +45,27 @@ class PathParameterDefinition(NamedTuple):
     full: str
     type: type
     parser: Callable[[str], Any] | None
+    pattern: Pattern[str] | None = None
+
+
+class PathSegmentPattern(NamedTuple):
+    """A path component mixing literal text and path parameters, e.g. ``{name:str}.{ext:str}``."""
+
+    template: str
+    pattern: Pattern[str]
+    parameters: tuple[PathParameterDefinition, ...]
+    literals: tuple[str, ...]
+
+    def render(self, values: Iterable[str]) -> str:
+        """Render the component with the given parameter values.
+
+        Args:
+            values: The values of the parameters, in the order of their declaration.
+
+        Returns:
+            The rendered path component.
+        """
+        return "".join(chain.from_iterable(zip(self.literals, [*values, ""])))
 
 
 def __getattr__(name: str) -> Any:
@@ -3,10 +3,12 @@ from __future__ import annotations
 import re
 from typing import Iterable
 
-__all__ = ("join_paths", "normalize_path")
+__all__ = ("canonicalize_path", "join_paths", "normalize_path", "normalize_route_path")
 
 
 multi_slash_pattern = re.compile("//+")
+control_characters_pattern = re.compile(r"[\x00-\x1f\x7f]")
+encoded_slash_pattern = re.compile(rb"%2f", re.IGNORECASE)
 
 
 def normalize_path(path: str) -> str:
@@ -23,6 +25,43 @@ def normalize_path(path: str) -> str:
     return multi_slash_pattern.sub("/", path)
 
 
+def normalize_route_path(path: str) -> str:
+    """Normalize the path template of a route when it is registered with the routing trie.
+
+    In addition to what :func:`normalize_path` does, surrounding whitespace is stripped and whitespace-only segments are
+    dropped. Request paths are only normalized by :func:`normalize_path`, since whitespace within them may be part of
+    the value of a path parameter.
+
+    Args:
+        path: Path string
+
+    Returns:
+        Path string
+    """
+    return normalize_path("/".join(segment for segment in path.split("/") if segment and not segment.isspace()).strip())
+
+
+def canonicalize_path(path: str) -> str:
+    """Bring a request path into its canonical form.
+
+    In addition to what :func:`normalize_path` does, ``.`` and ``..`` segments are resolved.
+
+    Args:
+        path: Path string
+
+    Returns:
+        Path string
+    """
+    segments: list[str] = []
+    for segment in path.split("/"):
+        if segment == "..":
+            if segments:
+                segments.pop()
+        elif segment != ".":
+            segments.append(segment)
+    return normalize_path("/".join(segments))
+
+
 def join_paths(paths: Iterable[str]) -> str:
     """Normalize and joins path fragments.
 
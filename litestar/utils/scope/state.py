@@ -26,8 +26,10 @@ class ScopeState:
 
     __slots__ = (
         "accept",
+        "api_version",
         "base_url",
         "body",
+        "cache_key",
         "content_type",
         "cookies",
         "csrf_token",
@@ -48,8 +50,10 @@ class ScopeState:
 
     def __init__(self) -> None:
         self.accept = Empty
+        self.api_version = Empty
         self.base_url = Empty
         self.body = Empty
+        self.cache_key = Empty
         self.content_type = Empty
         self.cookies = Empty
         self.csrf_token = Empty
@@ -68,8 +72,10 @@ class ScopeState:
         self._compat_ns: dict[str, Any] = {}
 
     accept: Accept | EmptyType
+    api_version: str | None | EmptyType
     base_url: URL | EmptyType
     body: bytes | EmptyType
+    cache_key: str | EmptyType
     content_type: tuple[str, dict[str, str]] | EmptyType
     cookies: dict[str, str] | EmptyType
     csrf_token: str | EmptyType
@@ -147,6 +147,49 @@ async def test_custom_cache_key(sync_to_thread: bool, anyio_backend: str, mock:
         assert await store.exists("/cached:::cached")
 
 
+async def test_cache_key_from_route(mock: MagicMock) -> None:
+    @get("/items/{item_id:int}", cache=True, sync_to_thread=False)
+    def handler(item_id: int) -> str:
+        return mock()  # type: ignore[no-any-return]
+
+    app = Litestar([handler], response_cache_config=ResponseCacheConfig(key_from_route=True, key_query_params=["q"]))
+
+    with TestClient(app) as client:
+        first_response = client.get("/items/1", params={"q": "a", "ignored": "x"})
+        second_response = client.get("/items/01/", params={"ignored": "y", "q": "a"})
+        assert first_response.text == second_response.text
+        assert mock.call_count == 1
+
+        client.get("/items/1", params={"q": "b"})
+        assert mock.call_count == 2
+
+    store = app.stores.get("response_cache")
+    assert await store.exists("GET /items/{item_id:int} item_id=1 q=a")
+
+
+async def test_cache_key_from_route_with_media_type_dispatch(mock: MagicMock) -> None:
+    @get("/items", cache=True, media_type="application/json", sync_to_thread=False)
+    def json_handler() -> str:
+        return mock()  # type: ignore[no-any-return]
+
+    @get("/items", cache=True, media_type="text/plain", sync_to_thread=False)
+    def text_handler() -> str:
+        return "text"
+
+    app = Litestar([json_handler, text_handler], response_cache_config=ResponseCacheConfig(key_from_route=True))
+
+    with TestClient(app) as client:
+        json_response = client.get("/items", headers={"Accept": "application/json"})
+        text_response = client.get("/items", headers={"Accept": "text/plain"})
+        assert json_response.headers["content-type"].startswith("application/json")
+        assert text_response.text == "text"
+        assert mock.call_count == 1
+
+    store = app.stores.get("response_cache")
+    assert await store.exists("GET /items   media_type=application%2Fjson")
+    assert await store.exists("GET /items   media_type=text%2Fplain")
+
+
 async def test_non_default_store_name(mock: MagicMock) -> None:
     @get(cache=True)
     def handler() -> str:
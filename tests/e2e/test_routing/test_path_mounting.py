@@ -8,7 +8,7 @@ from _pytest.monkeypatch import MonkeyPatch
 from litestar import Litestar, MediaType, asgi, get, websocket
 from litestar.exceptions import ImproperlyConfiguredException
 from litestar.response.base import ASGIResponse
-from litestar.status_codes import HTTP_200_OK
+from litestar.status_codes import HTTP_200_OK, HTTP_404_NOT_FOUND
 from litestar.testing import create_test_client
 
 if TYPE_CHECKING:
@@ -97,6 +97,90 @@ def test_does_not_support_asgi_handlers_on_same_level_as_websockets() -> None:
         Litestar(route_handlers=[asgi_handler, regular_handler])
 
 
+@pytest.mark.parametrize(
+    "request_path, expected_text",
+    [
+        ("/static", "mount:/"),
+        ("/static/app.js", "mount:/app.js"),
+        ("/static/static/app.js", "mount:/static/app.js"),
+        ("/static/data.js", "mount:/data.js"),
+        ("/static/data/1", "regular:1"),
+        ("/files/static-img.png", "files:static-img.png"),
+        ("/api/static", "api"),
+    ],
+)
+def test_mount_path_is_stripped_as_prefix(request_path: str, expected_text: str) -> None:
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+        response = ASGIResponse(body=f"mount:{scope['path']}".encode(), media_type=MediaType.TEXT)
+        await response(scope, receive, send)
+
+    @get("/static/data/{value:int}", media_type=MediaType.TEXT)
+    def regular_handler(value: int) -> str:
+        return f"regular:{value}"
+
+    @get("/files/{name:str}", media_type=MediaType.TEXT)
+    def files_handler(name: str) -> str:
+        return f"files:{name}"
+
+    @get("/api/static", media_type=MediaType.TEXT)
+    def api_handler() -> str:
+        return "api"
+
+    with create_test_client(route_handlers=[static_handler, regular_handler, files_handler, api_handler]) as client:
+        response = client.get(request_path)
+        assert response.status_code == HTTP_200_OK
+        assert response.text == expected_text
+
+
+def test_mount_path_does_not_match_partial_segment() -> None:
+    @asgi("/sub", is_mount=True)
+    async def asgi_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+        response = ASGIResponse(body=scope["path"].encode(), media_type=MediaType.TEXT)
+        await response(scope, receive, send)
+
+    with create_test_client(route_handlers=[asgi_handler]) as client:
+        assert client.get("/sub/path").text == "/path/"
+        assert client.get("/subpath").status_code == HTTP_404_NOT_FOUND
+        assert client.get("/other/sub/path").status_code == HTTP_404_NOT_FOUND
+
+
+def test_most_specific_mount_path_wins() -> None:
+    @asgi("/mount", is_mount=True)
+    async def outer_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+        response = ASGIResponse(body=f"outer:{scope['path']}".encode(), media_type=MediaType.TEXT)
+        await response(scope, receive, send)
+
+    @asgi("/mount/inner", is_mount=True)
+    async def inner_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+        response = ASGIResponse(body=f"inner:{scope['path']}".encode(), media_type=MediaType.TEXT)
+        await response(scope, receive, send)
+
+    with create_test_client(route_handlers=[outer_handler, inner_handler]) as client:
+        assert client.get("/mount/inner/x").text == "inner:/x/"
+        assert client.get("/mount/innerx").text == "outer:/innerx/"
+        assert client.get("/mount/x").text == "outer:/x/"
+
+
+def test_mount_path_is_moved_into_root_path() -> None:
+    @asgi("/sub/path", is_mount=True)
+    async def asgi_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+        response = ASGIResponse(body=f"{scope['root_path']}:{scope['path']}".encode(), media_type=MediaType.TEXT)
+        await response(scope, receive, send)
+
+    @asgi("/", is_mount=True)
+    async def root_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+        response = ASGIResponse(body=f"{scope['root_path']}:{scope['path']}".encode(), media_type=MediaType.TEXT)
+        await response(scope, receive, send)
+
+    with create_test_client(route_handlers=[asgi_handler, root_handler]) as client:
+        assert client.get("/sub/path/fragment").text == "/sub/path:/fragment/"
+        assert client.get("/other").text == ":/other/"
+
+    with create_test_client(route_handlers=[asgi_handler], root_path="/app") as client:
+        assert client.get("/sub/path/fragment").text == "/app/sub/path:/fragment/"
+
+
 @pytest.mark.parametrize(
     "server_command",
     [
@@ -5,14 +5,18 @@ import httpx
 import pytest
 from _pytest.monkeypatch import MonkeyPatch
 
-from litestar import Controller, MediaType, Router, delete, get, post
+from litestar import Controller, Litestar, MediaType, Request, Router, delete, get, post, route
+from litestar.config.versioning import VersioningConfig
+from litestar.exceptions import ImproperlyConfiguredException
 from litestar.status_codes import (
     HTTP_200_OK,
     HTTP_204_NO_CONTENT,
+    HTTP_308_PERMANENT_REDIRECT,
     HTTP_404_NOT_FOUND,
     HTTP_405_METHOD_NOT_ALLOWED,
 )
 from litestar.testing import create_test_client
+from litestar.types import EncodedSlashHandling
 
 
 @delete(sync_to_thread=False)
@@ -163,6 +167,70 @@ async def test_http_route_raises_for_unsupported_method(anyio_backend: str) -> N
     with create_test_client(route_handlers=[my_get_handler, my_post_handler]) as client:
         response = client.delete("/")
         assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+        assert response.headers["allow"] == "GET, OPTIONS, POST"
+
+
+def test_head_falls_back_to_get() -> None:
+    @get("/items", sync_to_thread=False)
+    def get_items() -> str:
+        return "items"
+
+    with create_test_client([get_items]) as client:
+        response = client.head("/items")
+        assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+        assert response.headers["allow"] == "GET, OPTIONS"
+
+    with create_test_client([get_items], head_falls_back_to_get=True) as client:
+        response = client.head("/items")
+        assert response.status_code == HTTP_200_OK
+        assert response.content == b""
+        assert response.headers["content-length"] == "5"
+
+        assert client.post("/items").headers["allow"] == "GET, HEAD, OPTIONS"
+
+
+@pytest.mark.parametrize(
+    "encoded_slash_handling, expected_status_code, expected_text",
+    [
+        ("split", HTTP_200_OK, "raw a"),
+        ("reject", HTTP_404_NOT_FOUND, None),
+        ("decode", HTTP_200_OK, "file a/raw"),
+        ("pass_through", HTTP_200_OK, "file a%2Fraw"),
+    ],
+)
+def test_encoded_slash_handling(
+    encoded_slash_handling: EncodedSlashHandling, expected_status_code: int, expected_text: Optional[str]
+) -> None:
+    @get("/files/{name:str}", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_file(name: str) -> str:
+        return f"file {name}"
+
+    @get("/files/{name:str}/raw", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_raw_file(name: str) -> str:
+        return f"raw {name}"
+
+    with create_test_client([get_file, get_raw_file], encoded_slash_handling=encoded_slash_handling) as client:
+        assert client.get("/files/b/raw").text == "raw b"
+
+        response = client.get("/files/a%2Fraw")
+        assert response.status_code == expected_status_code
+        if expected_text is not None:
+            assert response.text == expected_text
+
+
+@pytest.mark.parametrize(
+    "normalize_unicode_paths, expected_status_code", [(False, HTTP_404_NOT_FOUND), (True, HTTP_200_OK)]
+)
+def test_normalize_unicode_paths(normalize_unicode_paths: bool, expected_status_code: int) -> None:
+    # "e" followed by a combining acute accent, which NFC normalization composes into "\u00e9"
+    @get("/cafe\u0301/{name:str}", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_cafe(name: str) -> str:
+        return name
+
+    with create_test_client([get_cafe], normalize_unicode_paths=normalize_unicode_paths) as client:
+        response = client.get("/caf\u00e9/menu")
+        assert response.status_code == expected_status_code
+        assert client.get("/cafe\u0301/menu").status_code == HTTP_200_OK
 
 
 def test_path_order() -> None:
@@ -252,6 +320,11 @@ def test_support_for_path_type_parameters() -> None:
 
         response = client.get("/abc/a/b/c")
         assert response.status_code == HTTP_200_OK
+        assert response.text == "abc/a/b/c"
+
+        response = client.get("/abc/a//b/")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "abc/a/b"
 
 
 def test_base_path_param_resolution() -> None:
@@ -360,3 +433,262 @@ app = Litestar(route_handlers=[pathfinder], debug=True)
 
     assert httpx.get("http://127.0.0.1:9999/").text == "None"
     assert httpx.get("http://127.0.0.1:9999/something").text == "/something"
+
+
+@pytest.mark.parametrize(
+    "handler_path, request_path",
+    [
+        ("/path/", "/path"),
+        ("/path", "/path/"),
+        ("//path//sub//", "/path/sub"),
+        ("/path/sub", "/path//sub//"),
+        (" /path ", "/path"),
+        ("/path/ /sub", "/path/sub"),
+        ("/path/{value:int}/", "/path/1"),
+        ("//path//{value:int}", "/path//1/"),
+    ],
+)
+def test_registered_and_requested_paths_share_normalization(handler_path: str, request_path: str) -> None:
+    @get(handler_path)
+    def handler() -> None:
+        return None
+
+    with create_test_client(handler) as client:
+        assert client.get(request_path).status_code == HTTP_200_OK
+
+
+def test_whitespace_of_request_paths_is_preserved() -> None:
+    @get("/users/{name:str}", sync_to_thread=False)
+    def get_user(name: str) -> str:
+        return f"[{name}]"
+
+    @get("/items", sync_to_thread=False)
+    def get_items() -> str:
+        return "items"
+
+    with create_test_client([get_user, get_items]) as client:
+        assert client.get("/users/john%20").text == "[john ]"
+        assert client.get("/users/%20john").text == "[ john]"
+        assert client.get("/items/%20").status_code == HTTP_404_NOT_FOUND
+
+
+@pytest.mark.parametrize(
+    "handler_path, request_path",
+    [
+        ("/caf%C3%A9", "/café"),
+        ("/café", "/caf%C3%A9"),
+        ("/hello%20world", "/hello world"),
+        ("/hello world", "/hello%20world"),
+    ],
+)
+def test_percent_encoded_handler_paths_are_decoded(handler_path: str, request_path: str) -> None:
+    @get(handler_path)
+    def handler() -> None:
+        return None
+
+    with create_test_client(handler) as client:
+        assert client.get(request_path).status_code == HTTP_200_OK
+
+
+@pytest.mark.parametrize("request_path", ["/files/a%00", "/files%00/a", "/files/a%0A", "/files/a%7F"])
+def test_request_paths_with_control_characters_are_not_found(request_path: str) -> None:
+    @get("/files/{name:str}")
+    def handler(name: str) -> str:
+        return name
+
+    with create_test_client(handler) as client:
+        assert client.get("/files/a").status_code == HTTP_200_OK
+        assert client.get(request_path).status_code == HTTP_404_NOT_FOUND
+
+
+def test_path_parameters_are_defined_per_method() -> None:
+    @get("/items/{item_id:int}", media_type=MediaType.TEXT)
+    def get_item(item_id: int) -> str:
+        return f"get:{item_id!r}"
+
+    @delete("/items/{item_id:str}", status_code=HTTP_200_OK, media_type=MediaType.TEXT)
+    def delete_item(item_id: str) -> str:
+        return f"delete:{item_id!r}"
+
+    with create_test_client([get_item, delete_item]) as client:
+        response = client.get("/items/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "get:1"
+
+        response = client.delete("/items/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "delete:'1'"
+
+        response = client.delete("/items/abc")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "delete:'abc'"
+
+        assert client.get("/items/abc").status_code == HTTP_404_NOT_FOUND
+
+
+def test_regex_constrained_path_parameters() -> None:
+    @get(r"/items/{sku:str:^[A-Z]{3}-\d+$}", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_item(sku: str) -> str:
+        return sku
+
+    @get("/pages/{page:int:[1-9][0-9]?}", name="get_page", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_page(page: int) -> str:
+        return repr(page)
+
+    with create_test_client([get_item, get_page]) as client:
+        assert client.get("/items/ABC-123").text == "ABC-123"
+        assert client.get("/items/abc-123").status_code == HTTP_404_NOT_FOUND
+        assert client.get("/items/ABC-123x").status_code == HTTP_404_NOT_FOUND
+        assert client.get("/pages/12").text == "12"
+        assert client.get("/pages/0").status_code == HTTP_404_NOT_FOUND
+        assert client.get("/pages/123").status_code == HTTP_404_NOT_FOUND
+        assert client.app.route_reverse("get_page", page=5) == "/pages/5"
+
+
+def test_multiple_placeholders_in_one_segment() -> None:
+    @get("/files/{stem:str}.{ext:str}", name="get_file", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_file(stem: str, ext: str) -> str:
+        return f"{stem}|{ext}"
+
+    @get("/files/v{version:int}", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_version(version: int) -> str:
+        return repr(version)
+
+    @get("/files/readme.md", media_type=MediaType.TEXT, sync_to_thread=False)
+    def get_readme() -> str:
+        return "readme"
+
+    with create_test_client([get_file, get_version, get_readme]) as client:
+        assert client.get("/files/archive.tar.gz").text == "archive.tar|gz"
+        assert client.get("/files/v2").text == "2"
+        assert client.get("/files/readme.md").text == "readme"
+        assert client.get("/files/vx").status_code == HTTP_404_NOT_FOUND
+        assert client.get("/files/archive").status_code == HTTP_404_NOT_FOUND
+        assert client.app.route_reverse("get_file", stem="report", ext="pdf") == "/files/report.pdf"
+
+
+def test_media_type_versioned_handlers() -> None:
+    @get("/items", media_type="application/vnd.myapi.v1+json")
+    def get_items_v1() -> dict:
+        return {"version": 1}
+
+    @get("/items", media_type="application/vnd.myapi.v2+json")
+    def get_items_v2() -> dict:
+        return {"version": 2}
+
+    with create_test_client([get_items_v1, get_items_v2]) as client:
+        response = client.get("/items", headers={"Accept": "application/vnd.myapi.v2+json"})
+        assert response.json() == {"version": 2}
+        assert response.headers["content-type"].startswith("application/vnd.myapi.v2+json")
+
+        assert client.get("/items").json() == {"version": 1}
+        assert client.get("/items", headers={"Accept": "application/vnd.myapi.v3+json"}).json() == {"version": 1}
+        accept = "application/vnd.myapi.v1+json;q=0.5, application/vnd.myapi.v2+json"
+        response = client.get("/items", headers={"Accept": accept})
+        assert response.json() == {"version": 2}
+
+
+def test_wildcard_method_handler() -> None:
+    @get("/proxy/{rest:path}", sync_to_thread=False)
+    def get_handler(rest: Path) -> str:
+        return "get"
+
+    @route("/proxy/{rest:path}", http_method="*", sync_to_thread=False)
+    def fallback_handler(request: Request, rest: Path) -> str:
+        return f"{request.method} {rest}"
+
+    with create_test_client([get_handler, fallback_handler], openapi_config=None) as client:
+        assert client.get("/proxy/a").text == "get"
+        assert client.patch("/proxy/a/b").text == "PATCH /a/b"
+        assert client.delete("/proxy/a").text == "DELETE /a"
+        assert client.options("/proxy/a").text == "OPTIONS /a"
+
+
+def test_redirect_to_canonical_path() -> None:
+    @get("/items/{item_id:int}", sync_to_thread=False)
+    def get_item(item_id: int) -> int:
+        return item_id
+
+    with create_test_client([get_item], redirect_to_canonical_path=True) as client:
+        for path in ("/items//1", "/items/1/", "/other/../items/./1"):
+            response = client.get(path, params={"q": "a b"}, follow_redirects=False)
+            assert response.status_code == HTTP_308_PERMANENT_REDIRECT
+            assert response.headers["location"] == "/items/1?q=a+b"
+
+        response = client.get("/items/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "1"
+
+
+def test_host_based_routing() -> None:
+    @get("/", sync_to_thread=False)
+    def api_index() -> str:
+        return "api"
+
+    @get("/", sync_to_thread=False)
+    def tenant_index() -> str:
+        return "tenant"
+
+    @get("/", sync_to_thread=False)
+    def default_index() -> str:
+        return "default"
+
+    @get("/status", sync_to_thread=False, opt={"host": "status.example.com"})
+    def status() -> str:
+        return "status"
+
+    api_router = Router(path="/", route_handlers=[api_index], opt={"host": "api.example.com"})
+    tenant_router = Router(path="/", route_handlers=[tenant_index], opt={"host": "*.example.com"})
+
+    with create_test_client([api_router, tenant_router, default_index, status]) as client:
+        assert client.get("/", headers={"Host": "api.example.com"}).text == "api"
+        assert client.get("/", headers={"Host": "API.example.com:8000"}).text == "api"
+        assert client.get("/", headers={"Host": "acme.example.com"}).text == "tenant"
+        assert client.get("/", headers={"Host": "example.com"}).text == "default"
+        assert client.get("/", headers={"Host": "example.org"}).text == "default"
+
+        assert client.get("/status", headers={"Host": "status.example.com"}).text == "status"
+        assert client.get("/status", headers={"Host": "api.example.com"}).status_code == HTTP_404_NOT_FOUND
+
+
+def test_version_aware_routing() -> None:
+    @get("/items", sync_to_thread=False)
+    def list_items() -> str:
+        return "items"
+
+    @get("/items", sync_to_thread=False)
+    def list_items_v2() -> str:
+        return "items v2"
+
+    @get("/users", sync_to_thread=False)
+    def list_users() -> str:
+        return "users"
+
+    @get("/reports", sync_to_thread=False, opt={"version": "v2"})
+    def list_reports() -> str:
+        return "reports"
+
+    v2_router = Router(path="/", route_handlers=[list_items_v2], opt={"version": "v2"})
+    versioning_config = VersioningConfig(versions=["v1", "v2"], header="X-API-Version", path_prefix=True)
+
+    with create_test_client(
+        [list_items, list_users, list_reports, v2_router], versioning_config=versioning_config
+    ) as client:
+        assert client.get("/items").text == "items"
+        assert client.get("/v1/items").text == "items"
+        assert client.get("/v2/items").text == "items v2"
+        assert client.get("/items", headers={"X-API-Version": "v2"}).text == "items v2"
+        assert client.get("/v2/users").text == "users"
+        assert client.get("/v2/reports").text == "reports"
+        assert client.get("/reports").status_code == HTTP_404_NOT_FOUND
+        assert client.get("/v3/items").status_code == HTTP_404_NOT_FOUND
+
+
+@pytest.mark.parametrize("handler_path", ["/files/a%2Fb", "/files/a%2fb/{name:str}"])
+def test_encoded_slashes_in_handler_paths_are_rejected(handler_path: str) -> None:
+    @get(handler_path)
+    def handler() -> None:
+        return None
+
+    with pytest.raises(ImproperlyConfiguredException, match="encoded slashes"):
+        Litestar(route_handlers=[handler])
@@ -102,3 +102,19 @@ def test_route_reverse_validation() -> None:
 
     with pytest.raises(NoRouteMatchFoundException):
         app.route_reverse("another-handler-name", param=1)
+
+
+def test_url_path_for() -> None:
+    @get("/groups/{group_id:int}/users/{user_id:str}", name="membership")
+    def handler() -> None:
+        pass
+
+    router = Litestar(route_handlers=[Router("/api", route_handlers=[handler])]).asgi_router
+
+    assert router.url_path_for("membership", group_id=1, user_id="abc") == "/api/groups/1/users/abc"
+
+    with pytest.raises(NoRouteMatchFoundException, match="Missing path parameter user_id"):
+        router.url_path_for("membership", group_id=1)
+
+    with pytest.raises(NoRouteMatchFoundException, match="doesn't match declared type"):
+        router.url_path_for("membership", group_id="1", user_id="abc")
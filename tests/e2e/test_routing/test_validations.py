@@ -1,13 +1,16 @@
-from typing import Any
+from typing import TYPE_CHECKING, Any, Dict
 
 import pytest
 
-from litestar import Controller, Litestar, WebSocket, get, post, websocket
-from litestar.exceptions import ImproperlyConfiguredException
+from litestar import Controller, Litestar, WebSocket, asgi, get, post, websocket
+from litestar.exceptions import ImproperlyConfiguredException, RouteConflictException
 from litestar.static_files import StaticFilesConfig
 from litestar.status_codes import HTTP_200_OK
 from litestar.testing import create_test_client
 
+if TYPE_CHECKING:
+    from litestar.types import Receive, Scope, Send
+
 
 def test_register_validation_duplicate_handlers_for_same_route_and_method() -> None:
     @get(path="/first")
@@ -22,6 +25,83 @@ def test_register_validation_duplicate_handlers_for_same_route_and_method() -> N
         Litestar(route_handlers=[first_route_handler, second_route_handler])
 
 
+def test_register_validation_duplicate_handlers_with_inferred_media_types() -> None:
+    @get(path="/")
+    def text_handler() -> str:
+        return ""
+
+    @get(path="/")
+    def json_handler() -> Dict[str, str]:
+        return {}
+
+    @get(path="/", media_type="application/vnd.myapi.v2+json")
+    def declared_handler() -> Dict[str, str]:
+        return {}
+
+    with pytest.raises(ImproperlyConfiguredException, match="Handler already registered"):
+        Litestar(route_handlers=[text_handler, json_handler])
+
+    with pytest.raises(ImproperlyConfiguredException, match="Handler already registered"):
+        Litestar(route_handlers=[json_handler, declared_handler])
+
+
+def test_register_validation_conflict_names_both_handlers() -> None:
+    @get(path="/items/{item_id:int}")
+    def first_route_handler(item_id: int) -> None:
+        pass
+
+    @get(path="/items/{item_name:str}")
+    def second_route_handler(item_name: str) -> None:
+        pass
+
+    with pytest.raises(RouteConflictException) as exc_info:
+        Litestar(route_handlers=[first_route_handler, second_route_handler])
+
+    exc = exc_info.value
+    assert exc.path == "/items/{item_name:str}"
+    assert exc.handler_name == f"{__name__}.{second_route_handler.fn.__qualname__}"
+    assert exc.conflicting_path == "/items/{item_id:int}"
+    assert exc.conflicting_handler_name == f"{__name__}.{first_route_handler.fn.__qualname__}"
+    assert exc.handler_location and exc.handler_location.startswith(f"{__file__}:")
+    assert exc.conflicting_handler_location and exc.conflicting_handler_location.startswith(f"{__file__}:")
+    assert exc.handler_location in exc.detail
+    assert exc.conflicting_handler_location in exc.detail
+
+
+def test_register_validation_duplicate_websocket_handlers_for_same_route() -> None:
+    @websocket(path="/chat")
+    async def first_websocket_handler(socket: "WebSocket[Any, Any, Any]") -> None:
+        pass
+
+    @websocket(path="/chat")
+    async def second_websocket_handler(socket: "WebSocket[Any, Any, Any]") -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match="already registered for 'websocket' on the same path") as e:
+        Litestar(route_handlers=[first_websocket_handler, second_websocket_handler])
+
+    assert "first_websocket_handler" in str(e.value)
+    assert "second_websocket_handler" in str(e.value)
+
+
+def test_register_validation_invalid_path_parameter_constraint() -> None:
+    @get(path="/items/{sku:str:[A-Z}")
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match="Invalid constraint for path parameter 'sku'"):
+        Litestar(route_handlers=[handler])
+
+
+def test_register_validation_path_type_parameter_not_last() -> None:
+    @get(path="/files/{file_path:path}/edit")
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match="must be its last component"):
+        Litestar(route_handlers=[handler])
+
+
 def test_supports_websocket_and_http_handlers() -> None:
     @get(path="/")
     def http_handler() -> dict:
@@ -83,3 +163,115 @@ def test_validate_static_files_with_same_path_in_handler() -> None:
             StaticFilesConfig(directories=["uploads"], path="/uploads"),
         ],
     )
+
+
+@pytest.mark.parametrize("path", ["/files/{name:str}.json", "/files/prefix-{name:str}", "/files/{name:str"])
+def test_register_validation_placeholder_not_a_whole_segment(path: str) -> None:
+    @get(path=path)
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match="do not match its path template"):
+        Litestar(route_handlers=[handler])
+
+
+@pytest.mark.parametrize("path", ["/files/\x00", "/files%00/{name:str}", "/files/\x1b[0m", "/files/\x7f"])
+def test_register_validation_control_characters_in_path(path: str) -> None:
+    @get(path=path)
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match="control characters"):
+        Litestar(route_handlers=[handler])
+
+
+def test_register_validation_same_method_with_different_path_parameter_types() -> None:
+    @get(path="/items/{item_id:int}")
+    def int_handler(item_id: int) -> None:
+        pass
+
+    @get(path="/items/{item_id:str}")
+    def str_handler(item_id: str) -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match="each method of a path can only be handled once"):
+        Litestar(route_handlers=[int_handler, str_handler])
+
+
+def test_register_validation_path_type_parameter_sharing_position_with_other_types() -> None:
+    @get(path="/files/{file_path:path}")
+    def get_file(file_path: str) -> None:
+        pass
+
+    @post(path="/files/{file_id:int}/meta")
+    def update_file_meta(file_id: int) -> None:
+        pass
+
+    with pytest.raises(
+        ImproperlyConfiguredException,
+        match=r"Path parameter 'file_id' of type 'int' of route '/files/{file_id:int}/meta' conflicts with route "
+        r"'/files/{file_path:path}' \(GET\)",
+    ):
+        Litestar(route_handlers=[get_file, update_file_meta])
+
+
+def test_supports_websocket_and_http_handlers_with_path_parameters() -> None:
+    @get(path="/rooms/{room_id:int}")
+    def http_handler(room_id: int) -> dict:
+        return {"room": room_id}
+
+    @websocket(path="/rooms/{room_name:str}")
+    async def websocket_handler(socket: "WebSocket[Any, Any, Any]", room_name: str) -> None:
+        await socket.accept()
+        await socket.send_json({"room": room_name})
+        await socket.close()
+
+    with create_test_client([http_handler, websocket_handler]) as client:
+        response = client.get("/rooms/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.json() == {"room": 1}
+
+        with client.websocket_connect("/rooms/1") as ws:
+            assert ws.receive_json() == {"room": "1"}
+
+        with client.websocket_connect("/rooms/lobby") as ws:
+            assert ws.receive_json() == {"room": "lobby"}
+
+
+def test_register_validation_asgi_handler_sharing_path_names_both_handlers() -> None:
+    @asgi(path="/live")
+    async def asgi_handler(scope: "Scope", receive: "Receive", send: "Send") -> None:
+        pass
+
+    @websocket(path="/live")
+    async def websocket_handler(socket: "WebSocket[Any, Any, Any]") -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match=r"asgi_handler shares its path with .*websocket_handler"):
+        Litestar(route_handlers=[asgi_handler, websocket_handler])
+
+
+@pytest.mark.parametrize(
+    "path, match",
+    [
+        ("/items/{item_id:int", r"Unbalanced opening brace in path: '/items/{item_id:int' at byte offset 7"),
+        ("/items/item_id:int}", r"Unbalanced closing brace in path: '/items/item_id:int}' at byte offset 18"),
+        ("/items/{:int}", r"names should be of length greater than zero in path: '/items/{:int}' at byte offset 7"),
+        ("/items/{item_id:num}", r"declared with type 'num' in path: '/items/{item_id:num}' at byte offset 7"),
+        (
+            "/{id:int}/tags/{name:str}.{id:str}",
+            r"Duplicate path parameter 'id' in path: '/{id:int}/tags/{name:str}.{id:str}' at byte offset 26",
+        ),
+        (
+            "/café/{id:int}/{id:int}",
+            r"Duplicate path parameter 'id' in path: '/café/{id:int}/{id:int}' at byte offset 16",
+        ),
+    ],
+)
+def test_register_validation_path_template_syntax(path: str, match: str) -> None:
+    @get(path=path)
+    def handler() -> None:
+        pass
+
+    with pytest.raises(ImproperlyConfiguredException, match=match):
+        Litestar(route_handlers=[handler])
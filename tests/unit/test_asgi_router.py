@@ -1,21 +1,41 @@
 from __future__ import annotations
 
+import copy
+import json
+import pickle
 from contextlib import asynccontextmanager
 from typing import TYPE_CHECKING, AsyncGenerator, Callable
-from unittest.mock import AsyncMock, MagicMock
+from unittest import mock
+from unittest.mock import ANY, AsyncMock, MagicMock
+from uuid import UUID
 
 import pytest
 from pytest_mock import MockerFixture
+from typing_extensions import Annotated
 
-from litestar import Litestar, asgi
-from litestar._asgi.asgi_router import ASGIRouter
-from litestar.exceptions import ImproperlyConfiguredException
+from litestar import Litestar, asgi, delete, get, patch, post, route, websocket
+from litestar._asgi.asgi_router import ASGIRouter, MergeConflictPolicy
+from litestar._asgi.routing_trie.types import (
+    MatchResult,
+    PathParameterSentinel,
+    RouteMatch,
+    UnreachableRoute,
+    create_node,
+)
+from litestar._asgi.utils import PathParameterInfo, RouteDiff, RouteTableRow, get_scope_path
+from litestar.config.versioning import VersioningConfig
+from litestar.exceptions import ImproperlyConfiguredException, NoRouteMatchFoundException
+from litestar.params import Parameter
+from litestar.response.base import ASGIResponse
+from litestar.status_codes import HTTP_200_OK, HTTP_404_NOT_FOUND, HTTP_405_METHOD_NOT_ALLOWED
 from litestar.testing import TestClient, create_test_client
+from litestar.types import Empty
 from litestar.utils.helpers import get_exception_group
 
 if TYPE_CHECKING:
     from contextlib import AbstractAsyncContextManager
 
+    from litestar.connection import WebSocket
     from litestar.types import Receive, Scope, Send
 
 _ExceptionGroup = get_exception_group()
@@ -29,6 +49,185 @@ def test_add_mount_route_disallow_path_parameter() -> None:
         Litestar(route_handlers=[asgi("/mount-path", is_static=True)(handler), asgi("/mount-path/{id:str}")(handler)])
 
 
+def test_self_check_passes_for_consistent_trie() -> None:
+    async def asgi_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    @get("/")
+    def root_handler() -> None:
+        return None
+
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @post("/users/{user_id:int}/items/{item_id:str}")
+    def create_item(user_id: int, item_id: str) -> None:
+        return None
+
+    @websocket("/users/{user_id:int}")
+    async def user_socket(socket: WebSocket, user_id: int) -> None:
+        return None
+
+    app = Litestar(
+        route_handlers=[
+            root_handler,
+            get_user,
+            create_item,
+            user_socket,
+            asgi("/static", is_static=True)(asgi_handler),
+        ]
+    )
+    assert app.asgi_router.self_check() == []
+
+
+def test_self_check_reports_empty_nodes_and_parameter_mismatch() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user])
+    users_node = app.asgi_router.root_route_map_node.children["users"]
+    users_node.children["orphan"] = create_node()
+    placeholder_node = next(iter(node for key, node in users_node.children.items() if key != "orphan"))
+    placeholder_node.path_parameters["GET"] = ()
+
+    problems = app.asgi_router.self_check()
+
+    assert "node '/users/orphan' has neither handlers nor children" in problems
+    assert any("stores 0 path parameters for 'GET'" in problem for problem in problems)
+
+
+def test_self_check_reports_shadowed_handler() -> None:
+    @get("/items/{item_id:int}")
+    def get_item(item_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_item])
+    items_node = app.asgi_router.root_route_map_node.children["items"]
+    placeholder_node = next(iter(items_node.children.values()))
+    placeholder_node.asgi_handlers["GET"] = placeholder_node.asgi_handlers["OPTIONS"]
+
+    problems = app.asgi_router.self_check()
+
+    assert len(problems) == 1
+    assert "shadowed" in problems[0]
+
+
+def test_self_check_reports_routes_swallowed_by_static_path() -> None:
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    @post("/uploads")
+    def upload() -> None:
+        return None
+
+    @get("/static/reports/{report_id:int}")
+    def get_report(report_id: int) -> None:
+        return None
+
+    app = Litestar(
+        route_handlers=[
+            upload,
+            get_report,
+            asgi("/uploads", is_static=True)(static_handler),
+            asgi("/static", is_static=True, name="static")(static_handler),
+        ]
+    )
+    problems = app.asgi_router.self_check()
+
+    assert "route '/uploads' is registered under mount path '/uploads' and cannot be reached" in problems
+    assert not any("/static" in problem for problem in problems)
+
+
+def test_self_check_reports_mount_path_without_asgi_handler() -> None:
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    app = Litestar(route_handlers=[asgi("/static", is_static=True)(static_handler)])
+    app.asgi_router._mount_routes["/static"].is_mount = False
+
+    assert "mount path '/static' is not backed by an ASGI handler" in app.asgi_router.self_check()
+
+
+def test_trie_repr_is_independent_of_registration_order() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @post("/users/{user_id:int}")
+    def update_user(user_id: int) -> None:
+        return None
+
+    @get("/users/me")
+    def get_me() -> None:
+        return None
+
+    @get("/articles")
+    def get_articles() -> None:
+        return None
+
+    handlers = [get_user, update_user, get_me, get_articles]
+    first = Litestar(route_handlers=handlers)
+    second = Litestar(route_handlers=handlers[::-1])
+
+    assert repr(first.asgi_router.root_route_map_node) == repr(second.asgi_router.root_route_map_node)
+
+
+def test_self_check_reports_in_sorted_order() -> None:
+    @get("/b/{value:int}")
+    def b_handler(value: int) -> None:
+        return None
+
+    @get("/a/{value:int}")
+    def a_handler(value: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[b_handler, a_handler])
+    for key in ("b", "a"):
+        app.asgi_router.root_route_map_node.children[key].children["z"] = create_node()
+        app.asgi_router.root_route_map_node.children[key].children["y"] = create_node()
+
+    assert app.asgi_router.self_check() == [
+        "node '/a/y' has neither handlers nor children",
+        "node '/a/z' has neither handlers nor children",
+        "node '/b/y' has neither handlers nor children",
+        "node '/b/z' has neither handlers nor children",
+    ]
+
+
+@pytest.mark.parametrize(
+    "scope, expected",
+    [
+        ({"path": "/café", "raw_path": b"/other"}, "/café"),
+        ({"raw_path": b"/caf%C3%A9"}, "/café"),
+        ({"raw_path": b"/items/a%20b?q=1"}, "/items/a b"),
+    ],
+)
+def test_get_scope_path(scope: Scope, expected: str) -> None:
+    assert get_scope_path(scope) == expected
+
+
+def test_get_scope_path_without_path_and_raw_path() -> None:
+    with pytest.raises(ImproperlyConfiguredException):
+        get_scope_path({"type": "http"})  # type: ignore[arg-type]
+
+
+async def test_routing_falls_back_to_raw_path() -> None:
+    paths: list[str] = []
+
+    @asgi("/café/{name:str}")
+    async def handler(scope: Scope, receive: Receive, send: Send) -> None:
+        paths.append(scope["path"])
+
+    app = Litestar(route_handlers=[handler])
+    scope = {"type": "http", "method": "GET", "raw_path": b"/caf%C3%A9/x", "headers": [], "app": app, "state": {}}
+    await app.asgi_router(scope, AsyncMock(), AsyncMock())  # type: ignore[arg-type]
+
+    assert paths == ["/café/x"]
+    assert scope["path_params"] == {"name": "x"}
+
+
 class _LifeSpanCallable:
     def __init__(self, should_raise: bool = False) -> None:
         self.called = False
@@ -194,3 +393,1297 @@ async def test_lifespan_shutdown_failure(mock_format_exc: MagicMock) -> None:
 
     assert send.call_count == 2
     assert send.call_args_list[1][0][0] == {"type": "lifespan.shutdown.failed", "message": mock_format_exc.return_value}
+
+
+async def _echo_path_app(scope: Scope, receive: Receive, send: Send) -> None:
+    await ASGIResponse(body=f"{scope['path']}:{scope['path_params']}".encode())(scope, receive, send)
+
+
+def test_add_route_spec_dict() -> None:
+    app = Litestar()
+    route_handler = app.asgi_router.add_route_spec(
+        {
+            "path": "/items/{item_id:int}",
+            "handler": _echo_path_app,
+            "methods": ["GET", "PUT"],
+            "name": "item",
+            "path_parameters": ["item_id"],
+        }
+    )
+
+    assert app.route_reverse("item", item_id=1) == "/items/1"
+    assert route_handler.name == "item"
+
+    with TestClient(app) as client:
+        response = client.get("/items/1")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "/items/1:{'item_id': 1}"
+        assert client.put("/items/1").status_code == HTTP_200_OK
+        assert client.post("/items/1").status_code == HTTP_405_METHOD_NOT_ALLOWED
+
+
+def test_add_route_spec_tuple() -> None:
+    app = Litestar()
+    app.asgi_router.add_route_spec(("/health", None, _echo_path_app))
+
+    with TestClient(app) as client:
+        assert client.get("/health").text == "/health:{}"
+        assert client.delete("/health").status_code == HTTP_200_OK
+
+
+def test_add_route_spec_path_parameter_mismatch() -> None:
+    app = Litestar()
+    with pytest.raises(ImproperlyConfiguredException, match="do not match the placeholders"):
+        app.asgi_router.add_route_spec(
+            {"path": "/items/{item_id:int}", "handler": _echo_path_app, "path_parameters": ["id"]}
+        )
+
+
+def test_add_redirect() -> None:
+    app = Litestar()
+    app.asgi_router.add_redirect("/users/{user_id:int}/{file_path:path}", "/accounts/{user_id}/{file_path}", 301)
+
+    with TestClient(app) as client:
+        response = client.get("/users/1/avatars/me.png?size=64", follow_redirects=False)
+        assert response.status_code == 301
+        assert response.headers["location"] == "/accounts/1/avatars/me.png?size=64"
+
+    with pytest.raises(ImproperlyConfiguredException, match="not declared"):
+        app.asgi_router.add_redirect("/old", "/new/{user_id}")
+    with pytest.raises(ImproperlyConfiguredException, match="not a redirect status code"):
+        app.asgi_router.add_redirect("/old", "/new", 200)
+
+
+def test_registering_a_route_updates_resolved_paths() -> None:
+    @get("/users/{name:str}")
+    def get_user(name: str) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user])
+    assert app.asgi_router.handle_routing("/users/me", "GET")[1].handler_name == "get_user"
+
+    redirect_handler = app.asgi_router.add_redirect("/users/me", "/account")
+
+    assert app.asgi_router.handle_routing("/users/me", "GET")[1] is redirect_handler
+
+
+def test_route_for_name() -> None:
+    @get("/users/{user_id:int}", name="get_user")
+    def get_user(user_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user])
+    app.asgi_router.add_redirect("/people/{user_id:int}", "/users/{user_id}", name="people")
+
+    get_user_route = app.asgi_router.route_for_name("get_user")
+    assert get_user_route
+    assert (get_user_route.path, get_user_route.methods) == ("/users/{user_id:int}", ("GET",))
+    people_route = app.asgi_router.route_for_name("people")
+    assert people_route
+    assert (people_route.path, people_route.methods) == ("/people/{user_id:int}", ("asgi",))
+    assert app.asgi_router.route_for_name("unknown") is None
+
+
+def test_add_routes_from_starlette() -> None:
+    from starlette.routing import Mount, Route, WebSocketRoute
+
+    async def websocket_app(scope: Scope, receive: Receive, send: Send) -> None:
+        await send({"type": "websocket.accept"})
+        await send({"type": "websocket.close", "code": 1000})
+
+    app = Litestar()
+    app.asgi_router.add_routes(
+        [
+            Route("/items/{item_id:int}", _echo_path_app, methods=["GET"], name="item"),
+            WebSocketRoute("/ws", websocket_app),
+            Mount("/static", _echo_path_app),
+            ("/health", None, _echo_path_app),
+        ]
+    )
+
+    assert app.route_reverse("item", item_id=1) == "/items/1"
+
+    with TestClient(app) as client:
+        assert client.get("/items/1").text == "/items/1:{'item_id': 1}"
+        assert client.post("/items/1").status_code == HTTP_405_METHOD_NOT_ALLOWED
+        assert client.get("/static/css/site.css").text == "/css/site.css:{}"
+        assert client.get("/health").status_code == HTTP_200_OK
+        with client.websocket_connect("/ws"):
+            pass
+
+
+def test_add_routes_from_starlette_unsupported_convertor() -> None:
+    from starlette.convertors import CONVERTOR_TYPES, Convertor, register_url_convertor
+    from starlette.routing import Route
+
+    class DateConvertor(Convertor):
+        regex = r"\d{4}-\d{2}-\d{2}"
+
+        def convert(self, value: str) -> str:
+            return value
+
+        def to_string(self, value: str) -> str:
+            return value
+
+    with mock.patch.dict(CONVERTOR_TYPES):
+        register_url_convertor("spec_date", DateConvertor())
+
+        with pytest.raises(ImproperlyConfiguredException, match="DateConvertor"):
+            Litestar().asgi_router.add_routes([Route("/events/{day:spec_date}", _echo_path_app)])
+
+
+def test_openapi_paths() -> None:
+    @get("/")
+    def root_handler() -> None:
+        return None
+
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @post("/users/{user_id:int}")
+    def update_user(user_id: int) -> None:
+        return None
+
+    @websocket("/ws")
+    async def ws_handler(socket: WebSocket) -> None:
+        return None
+
+    app = Litestar(route_handlers=[root_handler, get_user, update_user, ws_handler])
+
+    assert app.asgi_router.openapi_paths() == {
+        "/": ("GET", "OPTIONS"),
+        "/users/{user_id}": ("GET", "OPTIONS", "POST"),
+    }
+
+
+def test_path_parameter_info() -> None:
+    @get("/users/{user_id:int}/items/{item_id:str}")
+    def get_item(user_id: Annotated[int, Parameter(ge=1)], item_id: Annotated[str, Parameter(max_length=8)]) -> None:
+        return None
+
+    @get("/health")
+    def health() -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_item, health])
+
+    assert app.asgi_router.path_parameter_info() == {
+        "/users/{user_id:int}/items/{item_id:str}": (
+            PathParameterInfo(name="user_id", type=int, required=True, constraints={"ge": 1}),
+            PathParameterInfo(name="item_id", type=str, required=True, constraints={"max_length": 8}),
+        ),
+        "/health": (),
+    }
+
+
+def test_route_table() -> None:
+    @get("/b", name="b-get")
+    def b_get() -> None:
+        return None
+
+    @post("/a", name="a-post", middleware=[lambda app: app])
+    def a_post() -> None:
+        return None
+
+    @websocket("/c", name="0-ws")
+    async def c_ws(socket: WebSocket) -> None:
+        return None
+
+    router = Litestar(route_handlers=[b_get, a_post, c_ws], openapi_config=None).asgi_router
+    rows = [row for row in router.table() if row.methods != ("OPTIONS",)]
+
+    assert rows == [
+        RouteTableRow(
+            path="/a",
+            scope_type="http",
+            methods=("POST",),
+            name="a-post",
+            handler="test_route_table.<locals>.a_post",
+            middleware_count=1,
+        ),
+        RouteTableRow(
+            path="/b",
+            scope_type="http",
+            methods=("GET",),
+            name="b-get",
+            handler="test_route_table.<locals>.b_get",
+            middleware_count=0,
+        ),
+        RouteTableRow(
+            path="/c",
+            scope_type="websocket",
+            methods=(),
+            name="0-ws",
+            handler="test_route_table.<locals>.c_ws",
+            middleware_count=0,
+        ),
+    ]
+    assert [row.name for row in router.table(sort_by="name") if row.name] == ["0-ws", "a-post", "b-get"]
+    assert [row.methods for row in router.table(sort_by="methods")][:2] == [(), ("GET",)]
+
+    with pytest.raises(ValueError):
+        router.table(sort_by="handler")  # type: ignore[arg-type]
+
+
+def test_resolve_asgi_app_writes_route_handler_and_template_to_scope() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user])
+    scope: Scope = {"type": "http", "path": "/users/1", "method": "GET"}  # type: ignore[typeddict-item]
+
+    assert app.asgi_router.resolve_asgi_app(scope)
+    assert scope["route_handler"] is app.route_handler_method_map["/users/{user_id:int}"]["GET"]
+    assert scope["path_params"] == {"user_id": 1}
+    assert scope["route_template"] == "/users/{user_id:int}"
+
+
+def test_resolve() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user, static_handler]).asgi_router
+    scope: Scope = {"type": "http", "path": "/users/1", "method": "GET"}  # type: ignore[typeddict-item]
+
+    result = router.resolve(scope)
+
+    assert result.path_template == "/users/{user_id:int}"
+    assert result.raw_path_params == {"user_id": "1"}
+    assert result.path_params == {"user_id": 1}
+    assert result.handler_type == "http"
+    assert result.handler is scope["route_handler"]
+
+    static_scope: Scope = {"type": "http", "path": "/static/app.js", "method": "GET"}  # type: ignore[typeddict-item]
+    assert router.resolve(static_scope).handler_type == "asgi"
+
+    router.set_fallback(_echo_path_app)
+    fallback_scope: Scope = {"type": "http", "path": "/unknown", "method": "GET"}  # type: ignore[typeddict-item]
+    assert router.resolve(fallback_scope) == MatchResult(_echo_path_app, None, {}, {}, None, None)
+
+
+@pytest.mark.parametrize(
+    "path, root_path, expected",
+    [
+        ("/app/items", "/app", "/items"),
+        ("/app", "/app", "/"),
+        ("/app/items", "/app/", "/items"),
+        ("/items", "/app", "/items"),
+        ("/application/items", "/app", "/application/items"),
+        ("/other/app/items", "/app", "/other/app/items"),
+        ("/items", "", "/items"),
+    ],
+)
+def test_get_route_path_strips_root_path(path: str, root_path: str, expected: str) -> None:
+    scope: Scope = {"type": "http", "path": path, "root_path": root_path}  # type: ignore[typeddict-item]
+    assert ASGIRouter.get_route_path(scope) == expected
+
+
+@pytest.mark.parametrize("route_on_raw_path, expected_name", [(False, "a%b"), (True, "a%25b")])
+def test_route_on_raw_path(route_on_raw_path: bool, expected_name: str) -> None:
+    @get("/files/{name:str}")
+    def get_file(name: str) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_file], route_on_raw_path=route_on_raw_path).asgi_router
+    # a path decoded twice, e.g. by a misbehaving proxy
+    scope: Scope = {  # type: ignore[typeddict-item]
+        "type": "http",
+        "path": "/files/a%b",
+        "raw_path": b"/files/a%2525b?download=1",
+        "method": "GET",
+    }
+
+    assert router.resolve(scope).path_params == {"name": expected_name}
+
+
+def test_route_listeners() -> None:
+    @get("/items")
+    def get_items() -> None:
+        return None
+
+    @post("/items")
+    def create_item() -> None:
+        return None
+
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_items])
+    events: list[tuple[str, str, set[str]]] = []
+    app.asgi_router.add_route_listener(lambda event, route: events.append((event, route.path, route.methods)))
+    replaced_app = app.asgi_router.handle_routing("/items", "GET")[0]
+
+    app.register(get_users)
+    app.register(create_item)
+
+    assert events == [
+        ("add", "/users", {"GET", "OPTIONS"}),
+        ("replace", "/items", {"GET", "POST", "OPTIONS"}),
+    ]
+    assert app.asgi_router.handle_routing("/items", "GET")[0] is not replaced_app
+
+
+def test_match_listeners() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user])
+    matches: list[tuple[str, str | None, str]] = []
+    app.asgi_router.add_match_listener(
+        lambda path_template, method, handler: matches.append((path_template, method, handler.handler_name))
+    )
+    scope: Scope = {"type": "http", "path": "/users/1", "method": "GET"}  # type: ignore[typeddict-item]
+
+    app.asgi_router.resolve_asgi_app(scope)
+
+    assert matches == [("/users/{user_id:int}", "GET", "get_user")]
+
+
+def test_set_fallback() -> None:
+    @get("/users/{user_id:int}", sync_to_thread=False)
+    def get_user(user_id: int) -> int:
+        return user_id
+
+    with create_test_client([get_user]) as client:
+        client.app.asgi_router.set_fallback(ASGIResponse(body=b"index"))
+
+        assert client.get("/users/1").json() == 1
+        assert client.get("/dashboard/settings").text == "index"
+        assert client.post("/users/1").status_code == HTTP_405_METHOD_NOT_ALLOWED
+
+        client.app.asgi_router.set_fallback(None)
+        assert client.get("/dashboard/settings").status_code == HTTP_404_NOT_FOUND
+
+
+async def test_router_dispatches_lifespan_scope() -> None:
+    startup_mock = AsyncMock()
+    shutdown_mock = AsyncMock()
+    router = ASGIRouter(app=Litestar(on_startup=[startup_mock], on_shutdown=[shutdown_mock]))
+    receive = AsyncMock(side_effect=[{"type": "lifespan.startup"}, {"type": "lifespan.shutdown"}])
+    send = AsyncMock()
+
+    await router({"type": "lifespan", "asgi": {"version": "3.0"}, "state": {}}, receive, send)  # type: ignore[arg-type]
+
+    startup_mock.assert_called_once()
+    shutdown_mock.assert_called_once()
+    assert [call[0][0] for call in send.call_args_list] == [
+        {"type": "lifespan.startup.complete"},
+        {"type": "lifespan.shutdown.complete"},
+    ]
+
+
+def test_referrers_report() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @websocket("/ws")
+    async def ws_handler(socket: WebSocket) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user, ws_handler], openapi_config=None)
+    report = app.asgi_router.referrers_report()
+
+    assert list(report) == ["/users/{user_id:int}", "/ws"]
+    user_route_handler = app.route_handler_method_map["/users/{user_id:int}"]["GET"]
+    assert any(obj is user_route_handler for obj in report["/users/{user_id:int}"])
+    assert any(obj is user_route_handler.fn for obj in report["/users/{user_id:int}"])
+    assert any(obj is app.route_handler_method_map["/ws"]["websocket"] for obj in report["/ws"])
+
+
+def test_dump_snapshot() -> None:
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_users, get_user, static_handler], openapi_config=None)
+    local = f"{__name__}.test_dump_snapshot.<locals>"
+    options = "litestar.routes.http.HTTPRoute.create_options_handler.<locals>.options_handler"
+
+    assert app.asgi_router.dump_snapshot() == (
+        "plain routes: /users\n"
+        "mount routes: /static\n"
+        "<root>\n"
+        f"  /static  handlers: asgi={local}.static_handler  flags: is_asgi, is_mount, is_static  params: asgi=()\n"
+        f"  /users  handlers: GET={local}.get_users, OPTIONS={options}  params: GET=(), OPTIONS=()\n"
+        f"  static  handlers: asgi={local}.static_handler  flags: is_asgi, is_mount, is_static  params: asgi=()\n"
+        "  users  flags: is_path_param_node\n"
+        f"    {{}}  handlers: GET={local}.get_user, OPTIONS={options}"
+        "  params: GET=(user_id:int), OPTIONS=(user_id:int)\n"
+    )
+
+
+def test_render_tree() -> None:
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    @get("/users/{user_id:int}/posts/{post_id:int}")
+    def get_post(user_id: int, post_id: int) -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_users, get_post, static_handler], openapi_config=None)
+
+    branch, last_branch = "\u251c\u2500\u2500 ", "\u2514\u2500\u2500 "
+
+    assert app.asgi_router.render_tree() == (
+        "/\n"
+        f"{branch}/users  GET, OPTIONS\n"
+        f"{branch}static  [static mount]\n"
+        f"{last_branch}users\n"
+        f"    {last_branch}{{user_id:int}}\n"
+        f"        {last_branch}posts\n"
+        f"            {last_branch}{{post_id:int}}  GET, OPTIONS\n"
+    )
+
+
+def test_to_dot() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user], openapi_config=None)
+
+    assert app.asgi_router.to_dot() == (
+        "digraph routes {\n"
+        "  node [shape=box];\n"
+        '  n0 [label="/"];\n'
+        '  n1 [label="users"];\n'
+        "  n0 -> n1;\n"
+        '  n2 [label="{user_id:int}\\nGET, OPTIONS"];\n'
+        "  n1 -> n2;\n"
+        "}\n"
+    )
+
+
+def test_to_json() -> None:
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @get(r"/items/{sku:str:^[A-Z]{3}-\d+$}")
+    def get_item(sku: str) -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_users, get_user, get_item, static_handler], openapi_config=None)
+    local = f"{__name__}.test_to_json.<locals>"
+    options = "litestar.routes.http.HTTPRoute.create_options_handler.<locals>.options_handler"
+
+    assert json.loads(app.asgi_router.to_json()) == {
+        "routes": [
+            {
+                "path": r"/items/{sku:str:^[A-Z]{3}-\d+$}",
+                "methods": ["GET", "OPTIONS"],
+                "handlers": {"GET": f"{local}.get_item", "OPTIONS": options},
+                "path_parameters": [{"name": "sku", "type": "str", "pattern": r"^[A-Z]{3}-\d+$"}],
+            },
+            {
+                "path": "/static",
+                "methods": ["asgi"],
+                "handlers": {"asgi": f"{local}.static_handler"},
+                "path_parameters": [],
+            },
+            {
+                "path": "/users",
+                "methods": ["GET", "OPTIONS"],
+                "handlers": {"GET": f"{local}.get_users", "OPTIONS": options},
+                "path_parameters": [],
+            },
+            {
+                "path": "/users/{user_id:int}",
+                "methods": ["GET", "OPTIONS"],
+                "handlers": {"GET": f"{local}.get_user", "OPTIONS": options},
+                "path_parameters": [{"name": "user_id", "type": "int", "pattern": None}],
+            },
+        ],
+        "plain_routes": ["/users"],
+        "mount_routes": [{"path": "/static", "is_static": True}],
+    }
+
+
+def test_from_spec() -> None:
+    @get("/users/{user_id:int}", name="get_user")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @post("/users/{user_id:int}", name="update_user")
+    def update_user(user_id: int) -> None:
+        return None
+
+    @get("/health", name="health")
+    def health() -> None:
+        return None
+
+    async def send_text(scope: Scope, receive: Receive, send: Send, text: str) -> None:
+        await send({"type": "http.response.start", "status": 200, "headers": []})
+        await send({"type": "http.response.body", "body": text.encode(), "more_body": False})
+
+    async def get_user_app(scope: Scope, receive: Receive, send: Send) -> None:
+        await send_text(scope, receive, send, f"get_user {scope['path_params']['user_id']}")
+
+    async def update_user_app(scope: Scope, receive: Receive, send: Send) -> None:
+        await send_text(scope, receive, send, "update_user")
+
+    async def health_app(scope: Scope, receive: Receive, send: Send) -> None:
+        await send_text(scope, receive, send, "ok")
+
+    apps = {"get_user": get_user_app, "update_user": update_user_app, "health": health_app}
+    spec = Litestar(route_handlers=[get_user, update_user, health], openapi_config=None).asgi_router.to_json()
+
+    router = ASGIRouter.from_spec(spec, handler_lookup=apps.get)
+
+    assert [entry.path for entry in router.routes()] == ["/health", "/users/{user_id:int}"]
+    with TestClient(router.app) as client:
+        assert client.get("/users/1").text == "get_user 1"
+        assert client.post("/users/1").text == "update_user"
+        assert client.get("/health").text == "ok"
+        response = client.delete("/users/1")
+        assert response.status_code == HTTP_405_METHOD_NOT_ALLOWED
+        assert response.headers["allow"] == "GET, POST"
+
+
+@get("/users/{user_id:int}")
+def get_pickled_user(user_id: int) -> None:
+    return None
+
+
+@get("/invoices")
+def get_pickled_invoices() -> None:
+    return None
+
+
+pickled_app = Litestar(route_handlers=[get_pickled_user], openapi_config=None)
+pickled_billing_app = Litestar(route_handlers=[get_pickled_invoices], openapi_config=None)
+
+
+def test_pickle() -> None:
+    billing_router = copy.copy(pickled_billing_app.asgi_router)
+    billing_router.import_path = f"{__name__}.pickled_billing_app"
+    router = copy.copy(pickled_app.asgi_router)
+    router.import_path = f"{__name__}.pickled_app"
+    router.mount("/billing", billing_router)
+    router.add_route_listener(MagicMock())
+    router.deprecated_route_hits["get_pickled_user"] = 2
+
+    restored = pickle.loads(pickle.dumps(router))
+
+    assert restored.app is pickled_app
+    assert restored.import_path == router.import_path
+    assert restored.root_route_map_node is not router.root_route_map_node
+    assert restored.dump_snapshot() == router.dump_snapshot()
+    assert restored.match("/users/1", "GET")
+    assert restored.match("/billing/invoices", "GET")
+    assert restored.deprecated_route_hits == {"get_pickled_user": 2}
+    assert restored._route_listeners == []
+
+
+def test_pickle_without_import_path() -> None:
+    with pytest.raises(TypeError):
+        pickle.dumps(copy.copy(pickled_app.asgi_router))
+
+
+def test_copy() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @get("/invoices")
+    def get_invoices() -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user], openapi_config=None).asgi_router
+    router_copy = copy.copy(router)
+    router_copy.mount("/billing", Litestar(route_handlers=[get_invoices], openapi_config=None).asgi_router)
+
+    assert router_copy.app is router.app
+    assert router_copy.match("/users/1", "GET")
+    assert router_copy.match("/billing/invoices", "GET")
+    assert router.match("/billing/invoices", "GET") is None
+
+
+def test_clone() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    async def health(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user], openapi_config=None).asgi_router
+    clone = router.clone()
+    clone.add_route_spec(("/health", ["GET"], health))
+
+    assert clone.app is not router.app
+    assert clone.app.asgi_router is clone
+    assert clone.match("/users/1", "GET")
+    assert clone.match("/health", "GET")
+    assert router.match("/health", "GET") is None
+    assert [route.path for route in router.app.routes] == ["/users/{user_id:int}"]
+
+
+def test_snapshot_and_restore() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user], openapi_config=None).asgi_router
+    snapshot = router.snapshot()
+
+    with pytest.raises(ImproperlyConfiguredException):
+        router.add_routes(
+            [("/items/{item_id:int}", ["GET"], _echo_path_app), ("/users/{id:int}", ["GET"], _echo_path_app)]
+        )
+    assert router.match("/items/1", "GET")
+
+    router.restore(snapshot)
+
+    assert router.match("/items/1", "GET") is None
+    assert router.match("/users/1", "GET")
+    assert [route.path for route in router.app.routes] == ["/users/{user_id:int}"]
+    assert router.self_check() == []
+
+    router.add_route_spec(("/items/{item_id:int}", ["GET"], _echo_path_app))
+    router.restore(snapshot)
+    assert router.match("/items/1", "GET") is None
+
+    with pytest.raises(ImproperlyConfiguredException, match="frozen"):
+        router.freeze().restore(snapshot)
+
+
+def test_version() -> None:
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    @post("/users")
+    def create_user() -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    version = Litestar(route_handlers=[get_users, create_user, static_handler]).asgi_router.version()
+
+    assert Litestar(route_handlers=[static_handler, create_user, get_users]).asgi_router.version() == version
+    assert Litestar(route_handlers=[get_users, static_handler]).asgi_router.version() != version
+    mount_handler = asgi("/static", is_mount=True)(static_handler.fn)
+    assert Litestar(route_handlers=[get_users, create_user, mount_handler]).asgi_router.version() != version
+
+
+def test_version_covers_routes_sharing_a_path_format() -> None:
+    @get("/items/{item_id:int}")
+    def get_item(item_id: int) -> None:
+        return None
+
+    @delete("/items/{item_id:str}", status_code=HTTP_200_OK)
+    def delete_item(item_id: str) -> None:
+        return None
+
+    version = Litestar(route_handlers=[get_item, delete_item]).asgi_router.version()
+
+    assert Litestar(route_handlers=[delete_item, get_item]).asgi_router.version() == version
+    assert Litestar(route_handlers=[get_item]).asgi_router.version() != version
+    assert Litestar(route_handlers=[delete_item]).asgi_router.version() != version
+
+
+def test_diff() -> None:
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    @post("/users")
+    def create_user() -> None:
+        return None
+
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @get("/users/{user_id:str}")
+    def get_user_by_name(user_id: str) -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    @websocket("/chat")
+    async def chat(socket: WebSocket) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_users, get_user, static_handler]).asgi_router
+    other = Litestar(route_handlers=[get_users, create_user, get_user_by_name, chat]).asgi_router
+
+    assert router.diff(router) == RouteDiff(added=[], removed=[], changed=[])
+    assert router.diff(other) == RouteDiff(
+        added=["websocket /chat"],
+        removed=["asgi /static static"],
+        changed=[
+            ("GET,OPTIONS /users", "GET,OPTIONS,POST /users"),
+            ("GET,OPTIONS /users/{user_id:int}", "GET,OPTIONS /users/{user_id:str}"),
+        ],
+    )
+
+
+def test_diff_routes_sharing_a_path_format() -> None:
+    @get("/items/{item_id:int}")
+    def get_item(item_id: int) -> None:
+        return None
+
+    @delete("/items/{item_id:str}", status_code=HTTP_200_OK)
+    def delete_item(item_id: str) -> None:
+        return None
+
+    @patch("/items/{item_id:str}")
+    def update_item(item_id: str) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_item, delete_item]).asgi_router
+    get_only = Litestar(route_handlers=[get_item]).asgi_router
+    other = Litestar(route_handlers=[get_item, update_item]).asgi_router
+
+    assert router.diff(router) == RouteDiff(added=[], removed=[], changed=[])
+    assert get_only.diff(router) == RouteDiff(added=["DELETE,OPTIONS /items/{item_id:str}"], removed=[], changed=[])
+    assert router.diff(get_only) == RouteDiff(added=[], removed=["DELETE,OPTIONS /items/{item_id:str}"], changed=[])
+    assert router.diff(other) == RouteDiff(
+        added=[],
+        removed=[],
+        changed=[("DELETE,OPTIONS /items/{item_id:str}", "OPTIONS,PATCH /items/{item_id:str}")],
+    )
+
+
+def test_remove_route() -> None:
+    @get("/users")
+    def get_users() -> str:
+        return "users"
+
+    @post("/users")
+    def create_user() -> None:
+        return None
+
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> int:
+        return user_id
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        await ASGIResponse(body=b"static")(scope, receive, send)
+
+    events: list[tuple[str, str]] = []
+    with create_test_client([get_users, create_user, get_user, static_handler]) as client:
+        router = client.app.asgi_router
+        router.add_route_listener(lambda event, route: events.append((event, route.path)))
+        assert client.get("/users/1").json() == 1
+
+        router.remove_route("/users/{user_id:int}")
+        router.remove_route("/users", "POST")
+        router.remove_route("/static")
+
+        assert events == [("remove", "/users/{user_id:int}"), ("replace", "/users"), ("remove", "/static")]
+        assert client.get("/users/1").status_code == 404
+        assert client.get("/static/app.js").status_code == 404
+        assert client.get("/users").text == "users"
+        assert client.post("/users").status_code == HTTP_405_METHOD_NOT_ALLOWED
+        assert client.options("/users").headers["allow"] == "GET, OPTIONS"
+        assert "users" not in router.root_route_map_node.children
+        assert router.self_check() == []
+
+        with pytest.raises(NoRouteMatchFoundException):
+            router.remove_route("/users", "DELETE")
+
+
+def test_replace_handler() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> int:
+        return user_id
+
+    @post("/users/{user_id:int}")
+    def update_user(user_id: int) -> str:
+        return "updated"
+
+    @get("/", name="get_user_v2")
+    def get_user_v2(user_id: int) -> int:
+        return user_id * 2
+
+    @post("/")
+    def create_user() -> None:
+        return None
+
+    events: list[tuple[str, str]] = []
+    with create_test_client([get_user, update_user]) as client:
+        router = client.app.asgi_router
+        router.add_route_listener(lambda event, route: events.append((event, route.path)))
+        assert client.get("/users/2").json() == 2
+        update_app = router.root_route_map_node.children["users"].children[PathParameterSentinel].asgi_handlers["POST"]
+
+        route_handler = router.replace_handler("/users/{user_id:int}", "GET", get_user_v2)
+
+        assert events == [("replace", "/users/{user_id:int}")]
+        assert client.get("/users/2").json() == 4
+        assert client.post("/users/2").text == "updated"
+        assert router.match("/users/2", "GET").handler is route_handler  # type: ignore[union-attr]
+        assert router.route_for_name("get_user_v2").path == "/users/{user_id:int}"  # type: ignore[union-attr]
+        assert (
+            router.root_route_map_node.children["users"].children[PathParameterSentinel].asgi_handlers["POST"]
+            is update_app
+        )
+        assert router.self_check() == []
+
+        with pytest.raises(NoRouteMatchFoundException):
+            router.replace_handler("/users/{user_id:int}", "DELETE", get_user_v2)
+        with pytest.raises(ImproperlyConfiguredException, match="must serve the methods GET"):
+            router.replace_handler("/users/{user_id:int}", "GET", create_user)
+
+
+def test_mount() -> None:
+    @get("/invoices/{invoice_id:int}")
+    def get_invoice(invoice_id: int) -> int:
+        return invoice_id
+
+    @get("/")
+    def billing_index() -> str:
+        return "billing"
+
+    @get("/health")
+    def health() -> str:
+        return "ok"
+
+    billing_app = Litestar([get_invoice, billing_index])
+    with create_test_client([health]) as client:
+        router = client.app.asgi_router
+        router.mount("/billing", billing_app.asgi_router)
+
+        assert client.get("/billing").text == "billing"
+        assert client.get("/billing/invoices/1").json() == 1
+        assert client.get("/invoices/1").status_code == 404
+        assert client.get("/health").text == "ok"
+        assert router.match("/billing/invoices/1", "GET") == RouteMatch(
+            path_template="/billing/invoices/{invoice_id:int}",
+            path_params={"invoice_id": "1"},
+            handler_type="http",
+            handler=ANY,
+            opt=ANY,
+        )
+        assert router.self_check() == []
+        assert billing_app.asgi_router.match("/invoices/1", "GET") is not None
+
+        with pytest.raises(ImproperlyConfiguredException):
+            router.mount("/billing", billing_app.asgi_router)
+
+        with pytest.raises(ImproperlyConfiguredException):
+            router.mount("/{tenant:str}", billing_app.asgi_router)
+
+
+@pytest.mark.parametrize(
+    "on_conflict, expected_health_text",
+    [("prefer_self", "app"), ("prefer_other", "plugin")],
+)
+def test_merge(on_conflict: MergeConflictPolicy, expected_health_text: str) -> None:
+    @get("/health", sync_to_thread=False)
+    def app_health() -> str:
+        return "app"
+
+    @get("/health", sync_to_thread=False)
+    def plugin_health() -> str:
+        return "plugin"
+
+    @get("/plugin/items/{item_id:int}", sync_to_thread=False)
+    def get_item(item_id: int) -> int:
+        return item_id
+
+    plugin_app = Litestar([plugin_health, get_item])
+    with create_test_client([app_health]) as client:
+        router = client.app.asgi_router
+        with pytest.raises(ImproperlyConfiguredException):
+            router.merge(plugin_app.asgi_router)
+
+        router.merge(plugin_app.asgi_router, on_conflict=on_conflict)
+
+        assert client.get("/health").text == expected_health_text
+        assert client.get("/plugin/items/1").json() == 1
+        assert router.self_check() == []
+
+
+def test_freeze() -> None:
+    @get("/users/{org:str}/{team:str}/{user_id:int}")
+    def get_user(org: str, team: str, user_id: int) -> None:
+        return None
+
+    @get("/files/{file_path:path}")
+    def get_file(file_path: str) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user, get_file], openapi_config=None).asgi_router
+    frozen_router = router.freeze()
+
+    _, handler, _, path_params, path_template, raw_path_params = frozen_router.handle_routing(
+        "/users/acme/core/1", "GET"
+    )
+    assert handler.handler_name == "get_user"
+    assert path_params == {"org": "acme", "team": "core", "user_id": 1}
+    assert raw_path_params == {"org": "acme", "team": "core", "user_id": "1"}
+    assert path_template == "/users/{org:str}/{team:str}/{user_id:int}"
+    assert frozen_router.handle_routing("/files/a/b.txt", "GET")[3] == {"file_path": "/a/b.txt"}
+    assert frozen_router.match("/users/acme/core", "GET") is None
+    assert copy.copy(frozen_router) is frozen_router
+
+    with pytest.raises(ImproperlyConfiguredException):
+        frozen_router.remove_route("/files/{file_path:path}")
+
+    router.remove_route("/files/{file_path:path}")
+    assert frozen_router.match("/files/a/b.txt", "GET")
+
+
+def test_freeze_compresses_literal_chains() -> None:
+    @get("/api/v1/users/{user_id:int}/profile/settings")
+    def get_settings(user_id: int) -> None:
+        return None
+
+    @get("/api/v1/users/{user_id:int}/posts")
+    def get_posts(user_id: int) -> None:
+        return None
+
+    frozen_router = Litestar(route_handlers=[get_settings, get_posts], openapi_config=None).asgi_router.freeze()
+    root = frozen_router.frozen_trie.root
+    api_node = root.children[root.child_keys.index("api")]
+    assert root.child_key_suffixes[root.child_keys.index("api")] == ("v1", "users")
+    assert api_node.placeholder is not None
+    assert api_node.placeholder.child_key_suffixes == ((), ("settings",))
+
+    assert frozen_router.handle_routing("/api/v1/users/1/profile/settings", "GET")[1].handler_name == "get_settings"
+    assert frozen_router.handle_routing("/api/v1/users/1/posts", "GET")[1].handler_name == "get_posts"
+    for path in ("/api/v1", "/api/v1/users/1/profile", "/api/v1/users/1/profile/other", "/api/v2/users/1/posts"):
+        assert frozen_router.match(path, "GET") is None
+
+
+def test_path_components_are_interned() -> None:
+    @get("/api/v1/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @get("/api/v1/items/{item_id:int}")
+    def get_item(item_id: int) -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user, get_item], openapi_config=None)
+    routes = {route.path: route for route in app.routes}
+    user_components = routes["/api/v1/users/{user_id:int}"].path_components
+    item_components = routes["/api/v1/items/{item_id:int}"].path_components
+    assert user_components[0] is item_components[0]
+    assert user_components[1] is item_components[1]
+    assert next(key for key in app.asgi_router.root_route_map_node.children if key == "api") is user_components[0]
+
+
+def test_routes() -> None:
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    @websocket("/chat")
+    async def chat(socket: WebSocket) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_users, get_user, static_handler, chat]).asgi_router
+
+    assert [(entry.path, entry.methods) for entry in router] == [
+        ("/chat", ("websocket",)),
+        ("/static", ("asgi",)),
+        ("/users", ("GET",)),
+        ("/users", ("OPTIONS",)),
+        ("/users/{user_id:int}", ("GET",)),
+        ("/users/{user_id:int}", ("OPTIONS",)),
+    ]
+    assert router.routes()[2].handler.fn is get_users.fn
+    assert list(router) == router.routes()
+
+
+def test_len_and_stats() -> None:
+    @get("/users")
+    def get_users() -> None:
+        return None
+
+    @post("/users")
+    def create_user() -> None:
+        return None
+
+    @get("/users/{user_id:int}/posts/{post_id:int}")
+    def get_post(user_id: int, post_id: int) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_users, create_user, get_post], openapi_config=None).asgi_router
+    stats = router.stats()
+
+    assert len(router) == 2
+    # the root, "/users" and the components of "/users/{user_id:int}/posts/{post_id:int}"
+    assert stats.node_count == 6
+    assert stats.max_depth == 4
+    assert stats.placeholder_count == 2
+    assert stats.estimated_memory > 0
+
+
+def test_match() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @asgi("/static", is_static=True)
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    @websocket("/chat/{room:str}")
+    async def chat(socket: WebSocket) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user, static_handler, chat]).asgi_router
+
+    match = router.match("/users/1", "GET")
+    assert match
+    assert match.path_template == "/users/{user_id:int}"
+    assert match.path_params == {"user_id": "1"}
+    assert match.handler_type == "http"
+    assert match.handler.fn is get_user.fn
+
+    assert router.match("/chat/lobby") == RouteMatch(
+        path_template="/chat/{room:str}",
+        path_params={"room": "lobby"},
+        handler_type="websocket",
+        handler=ANY,
+        opt=ANY,
+    )
+    assert router.match("/static/app.js", "GET") == RouteMatch(
+        path_template="/static", path_params={}, handler_type="asgi", handler=ANY, opt=ANY
+    )
+    assert router.match("/users/abc", "GET") is None
+    assert router.match("/users/1", "POST") is None
+    assert router.match("/unknown", "GET") is None
+
+
+def test_metadata_for() -> None:
+    @get("/users/{user_id:int}", opt={"owner": "accounts"})
+    def get_user(user_id: int) -> None:
+        return None
+
+    async def health(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user], opt={"slo": 0.99}).asgi_router
+    router.add_route_spec({"path": "/health", "handler": health, "opt": {"tags": ["ops"]}})
+
+    assert router.metadata_for("/users/1", "GET") == {"slo": 0.99, "owner": "accounts"}
+    assert router.metadata_for("/health", "GET") == {"slo": 0.99, "tags": ["ops"]}
+    assert router.metadata_for("/unknown", "GET") is None
+    match = router.match("/users/1", "GET")
+    assert match
+    assert match.opt == {"slo": 0.99, "owner": "accounts"}
+
+
+def test_explain() -> None:
+    @get("/users/{user_id:int}/avatar")
+    def get_avatar(user_id: int) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_avatar], openapi_config=None).asgi_router
+
+    assert [(step["component"], step["decision"], step["matched"]) for step in router.explain("/users/1/avatar")] == [
+        ("users", "literal", True),
+        ("1", "placeholder", True),
+        ("avatar", "literal", True),
+        (None, "handlers", True),
+    ]
+    steps = router.explain("/users/1/banner")
+    assert steps[-1]["component"] == "banner"
+    assert steps[-1]["decision"] == "no_match"
+    assert not steps[-1]["matched"]
+    assert "avatar" in steps[-1]["detail"]
+    assert router.explain("/users/1")[-1] == {
+        "component": None,
+        "decision": "handlers",
+        "matched": False,
+        "detail": "No route handler is registered at this node",
+    }
+
+
+def test_contains_and_allowed_methods() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @post("/users/{user_id:int}")
+    def update_user(user_id: int) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_user, update_user], openapi_config=None).asgi_router
+
+    assert "/users/1" in router
+    assert "/users" not in router
+    assert router.allowed_methods("/users/1") == {"GET", "POST", "OPTIONS"}
+    assert router.allowed_methods("/users/{user_id:int}") == {"GET", "POST", "OPTIONS"}
+    assert router.allowed_methods("/users") == set()
+
+
+def test_validate_reports_unreachable_routes() -> None:
+    async def static_handler(scope: Scope, receive: Receive, send: Send) -> None:
+        return None
+
+    @post("/uploads")
+    def upload() -> None:
+        return None
+
+    @get("/files/{name:str}.json")
+    def get_file(name: str) -> None:
+        return None
+
+    @post("/files/{file_id:int}.json")
+    def replace_file(file_id: int) -> None:
+        return None
+
+    @get("/v2/items")
+    def get_items() -> None:
+        return None
+
+    app = Litestar(
+        route_handlers=[upload, get_file, replace_file, get_items, asgi("/uploads", is_static=True)(static_handler)],
+        versioning_config=VersioningConfig(versions=["v1", "v2"], path_prefix=True),
+    )
+
+    assert app.asgi_router.validate() == [
+        UnreachableRoute(
+            path="/files/{file_id:int}.json",
+            methods=("OPTIONS", "POST"),
+            reason="segment '{file_id:int}.json' is pre-empted by segment '{name:str}.json'",
+        ),
+        UnreachableRoute(
+            path="/uploads",
+            methods=("OPTIONS", "POST"),
+            reason="requests are passed to the app mounted at '/uploads'",
+        ),
+        UnreachableRoute(
+            path="/v2/items",
+            methods=("GET", "OPTIONS"),
+            reason="the API version 'v2' is stripped from request paths before routing",
+        ),
+    ]
+
+
+def test_validate_passes_for_reachable_routes() -> None:
+    @get("/files/{name:str}.json")
+    def get_file(name: str) -> None:
+        return None
+
+    @get("/files/{name:str}.{ext:str}")
+    def get_any_file(name: str, ext: str) -> None:
+        return None
+
+    assert Litestar(route_handlers=[get_file, get_any_file]).asgi_router.validate() == []
+
+
+def test_warm_up() -> None:
+    @get("/users/{user_id:int}/posts/{post_id:uuid}")
+    def get_post(user_id: int, post_id: UUID) -> None:
+        return None
+
+    @websocket("/chat/{room:str}")
+    async def chat(socket: WebSocket, room: str) -> None:
+        return None
+
+    router = Litestar(route_handlers=[get_post, chat]).asgi_router
+    router.handle_routing.cache_clear()
+
+    report = router.warm_up()
+
+    assert list(report) == ["/chat/{room:str}", "/users/{user_id:int}/posts/{post_id:uuid}"]
+    assert all(duration >= 0 for duration in report.values())
+    assert router.handle_routing.cache_info().currsize == 3
+    assert get_post._response_handler_mapping["default_handler"] is not Empty
+
+
+def test_coverage_report() -> None:
+    @get("/users/{user_id:int}")
+    def get_user(user_id: int) -> None:
+        return None
+
+    @post("/users/{user_id:int}")
+    def update_user(user_id: int) -> None:
+        return None
+
+    @get("/health")
+    def health() -> None:
+        return None
+
+    app = Litestar(route_handlers=[get_user, update_user, health], openapi_config=None)
+
+    with pytest.raises(ImproperlyConfiguredException):
+        app.asgi_router.coverage_report()
+
+    app.asgi_router.enable_coverage()
+    with TestClient(app) as client:
+        client.get("/users/1")
+
+    assert app.asgi_router.coverage_report() == ["GET /health", "POST /users/{user_id:int}"]
+    assert app.asgi_router.coverage_report(ignore_methods=()) == [
+        "GET /health",
+        "OPTIONS /health",
+        "OPTIONS /users/{user_id:int}",
+        "POST /users/{user_id:int}",
+    ]
+
+
+def test_handler_serving_several_methods_shares_middleware_stack() -> None:
+    @route("/items", http_method=["GET", "POST"])
+    def items() -> None:
+        return None
+
+    @route("/limited", http_method=["GET", "POST"], max_concurrency=1)
+    def limited() -> None:
+        return None
+
+    app = Litestar(route_handlers=[items, limited], openapi_config=None)
+    items_node = app.asgi_router.root_route_map_node.children["/items"]
+    limited_node = app.asgi_router.root_route_map_node.children["/limited"]
+
+    assert items_node.asgi_handlers["GET"].asgi_app is items_node.asgi_handlers["POST"].asgi_app
+    assert limited_node.asgi_handlers["GET"].asgi_app is not limited_node.asgi_handlers["POST"].asgi_app
+
+    app.asgi_router.enable_coverage()
+    with TestClient(app) as client:
+        client.get("/items")
+
+    assert app.asgi_router.coverage_report() == ["GET /limited", "POST /items", "POST /limited"]
@@ -11,11 +11,12 @@ from litestar.exceptions import (
     HTTPException,
     ImproperlyConfiguredException,
     LitestarException,
+    MethodNotAllowedException,
     MissingDependencyException,
     ValidationException,
 )
 from litestar.middleware.exceptions.middleware import create_exception_response
-from litestar.status_codes import HTTP_400_BAD_REQUEST, HTTP_500_INTERNAL_SERVER_ERROR
+from litestar.status_codes import HTTP_400_BAD_REQUEST, HTTP_405_METHOD_NOT_ALLOWED, HTTP_500_INTERNAL_SERVER_ERROR
 from litestar.testing import RequestFactory, create_test_client
 
 
@@ -96,6 +97,15 @@ def test_validation_exception() -> None:
     assert isinstance(result, ValueError)
 
 
+def test_method_not_allowed_exception_allowed_methods() -> None:
+    result = MethodNotAllowedException(allowed_methods=["POST", "GET"], headers={"X-Custom": "1"})
+    assert result.status_code == HTTP_405_METHOD_NOT_ALLOWED
+    assert result.allowed_methods == {"GET", "POST"}
+    assert result.headers == {"X-Custom": "1", "Allow": "GET, POST"}
+
+    assert MethodNotAllowedException().headers is None
+
+
 @pytest.mark.parametrize("media_type", [MediaType.JSON, MediaType.TEXT])
 def test_create_exception_response_utility_litestar_http_exception(media_type: MediaType) -> None:
     exc = HTTPException(detail="litestar http exception", status_code=HTTP_400_BAD_REQUEST, extra=["any"])
@@ -2,7 +2,7 @@ from datetime import date, datetime, time, timedelta
 from decimal import Decimal
 from pathlib import Path
 from typing import Any, Optional
-from unittest.mock import MagicMock
+from unittest.mock import MagicMock, patch
 from uuid import UUID, uuid1, uuid4
 
 import pytest
@@ -10,7 +10,8 @@ import pytest
 from litestar import Litestar, MediaType, get, post
 from litestar.exceptions import ImproperlyConfiguredException
 from litestar.params import Parameter
-from litestar.status_codes import HTTP_200_OK, HTTP_201_CREATED, HTTP_400_BAD_REQUEST
+from litestar.routes.base import custom_parsers_map, param_type_map, register_path_parameter_type
+from litestar.status_codes import HTTP_200_OK, HTTP_201_CREATED, HTTP_400_BAD_REQUEST, HTTP_404_NOT_FOUND
 from litestar.testing import create_test_client
 
 
@@ -197,3 +198,32 @@ def test_optional_path_parameter() -> None:
         response = client.get("/hello")
         assert response.status_code == HTTP_200_OK
         assert response.text == "hello"
+
+
+def _parse_isbn(value: str) -> str:
+    digits = value.replace("-", "")
+    if len(digits) != 13 or not digits.isdigit():
+        raise ValueError(f"{value!r} is not an ISBN-13")
+    if sum(int(digit) * (3 if i % 2 else 1) for i, digit in enumerate(digits)) % 10:
+        raise ValueError(f"{value!r} has an invalid check digit")
+    return digits
+
+
+@patch.dict(custom_parsers_map)
+@patch.dict(param_type_map)
+def test_custom_path_parameter_type() -> None:
+    register_path_parameter_type("isbn", _parse_isbn)
+
+    @get("/books/{isbn:isbn}", media_type=MediaType.TEXT)
+    def get_book(isbn: str) -> str:
+        return isbn
+
+    with create_test_client(route_handlers=[get_book]) as client:
+        response = client.get("/books/978-3-16-148410-0")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "9783161484100"
+        assert client.get("/books/978-3-16-148410-1").status_code == HTTP_404_NOT_FOUND
+        assert client.get("/books/not-a-book").status_code == HTTP_404_NOT_FOUND
+
+    with pytest.raises(ImproperlyConfiguredException):
+        register_path_parameter_type("isbn", _parse_isbn)
@@ -5,7 +5,7 @@ from unittest.mock import MagicMock
 
 import pytest
 
-from litestar import MediaType, WebSocket, get, websocket
+from litestar import MediaType, Response, WebSocket, get, websocket
 from litestar.config.compression import CompressionConfig
 from litestar.enums import CompressionEncoding
 from litestar.exceptions import ImproperlyConfiguredException
@@ -221,6 +221,23 @@ def test_dont_recompress_cached(backend: Literal["gzip", "brotli"], compression_
     assert int(response.headers["Content-Length"]) < 40000
 
 
+def test_dont_recompress_encoded_response() -> None:
+    body = zlib.compress(b"_litestar_" * 4000)
+
+    @get(path="/", media_type=MediaType.TEXT)
+    def handler_fn() -> Response[bytes]:
+        return Response(body, headers={"content-encoding": "deflate"})
+
+    with create_test_client(
+        route_handlers=[handler_fn], compression_config=CompressionConfig(backend="gzip")
+    ) as client:
+        response = client.get("/", headers={"Accept-Encoding": "gzip, deflate"})
+
+    assert response.status_code == HTTP_200_OK
+    assert response.headers["Content-Encoding"] == "deflate"
+    assert response.text == "_litestar_" * 4000
+
+
 def test_compression_with_custom_backend(handler: HTTPRouteHandler) -> None:
     class ZlibCompression(CompressionFacade):
         encoding = "deflate"
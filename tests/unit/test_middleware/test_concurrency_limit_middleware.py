@@ -0,0 +1,77 @@
+from typing import TYPE_CHECKING, List
+from unittest.mock import AsyncMock
+
+import anyio
+import pytest
+
+from litestar import get
+from litestar.exceptions import ServiceUnavailableException
+from litestar.middleware.concurrency_limit import ConcurrencyLimitMiddleware
+from litestar.status_codes import HTTP_200_OK
+from litestar.testing import create_test_client
+
+if TYPE_CHECKING:
+    from litestar.types import Receive, Scope, Send
+
+
+class _BlockingApp:
+    def __init__(self) -> None:
+        self.started = anyio.Event()
+        self.release = anyio.Event()
+        self.calls = 0
+
+    async def __call__(self, scope: "Scope", receive: "Receive", send: "Send") -> None:
+        self.calls += 1
+        self.started.set()
+        await self.release.wait()
+
+
+async def test_concurrency_limit_rejects_excess_connections() -> None:
+    app = _BlockingApp()
+    middleware = ConcurrencyLimitMiddleware(app=app, max_concurrency=1)
+    scope: "Scope" = {"type": "http"}  # type: ignore[typeddict-item]
+
+    async with anyio.create_task_group() as task_group:
+        task_group.start_soon(middleware, scope, AsyncMock(), AsyncMock())
+        await app.started.wait()
+
+        with pytest.raises(ServiceUnavailableException):
+            await middleware(scope, AsyncMock(), AsyncMock())
+
+        app.release.set()
+
+    assert middleware.in_flight == 0
+    await middleware(scope, AsyncMock(), AsyncMock())
+    assert app.calls == 2
+
+
+async def test_concurrency_limit_queues_excess_connections() -> None:
+    app = _BlockingApp()
+    middleware = ConcurrencyLimitMiddleware(app=app, max_concurrency=1, queue_excess=True)
+    scope: "Scope" = {"type": "http"}  # type: ignore[typeddict-item]
+    finished: List[int] = []
+
+    async def call(index: int) -> None:
+        await middleware(scope, AsyncMock(), AsyncMock())
+        finished.append(index)
+
+    async with anyio.create_task_group() as task_group:
+        task_group.start_soon(call, 1)
+        await app.started.wait()
+        task_group.start_soon(call, 2)
+        await anyio.sleep(0.01)
+        assert app.calls == 1
+
+        app.release.set()
+
+    assert finished == [1, 2]
+    assert app.calls == 2
+
+
+def test_concurrency_limit_applied_from_opt() -> None:
+    @get("/", max_concurrency=2)
+    def handler() -> str:
+        return "ok"
+
+    with create_test_client([handler]) as client:
+        assert client.get("/").status_code == HTTP_200_OK
@@ -2,11 +2,12 @@ from typing import Any, Dict, List, Literal, Mapping, Optional, Union, cast
 
 import pytest
 
-from litestar import get
+from litestar import Router, get, post
 from litestar.config.cors import CORSConfig
 from litestar.middleware.cors import CORSMiddleware
-from litestar.status_codes import HTTP_200_OK, HTTP_404_NOT_FOUND
+from litestar.status_codes import HTTP_200_OK, HTTP_204_NO_CONTENT, HTTP_400_BAD_REQUEST, HTTP_404_NOT_FOUND
 from litestar.testing import create_test_client
+from litestar.types import ASGIApp, Receive, Scope, Send
 from litestar.types.asgi_types import Method
 
 
@@ -120,3 +121,48 @@ def test_cors_applied_on_exception_response_if_origin_is_present(
             assert response.headers.get("Access-Control-Allow-Origin") == origin
         else:
             assert not response.headers.get("Access-Control-Allow-Origin")
+
+
+def test_route_cors_config_answers_preflight_before_middleware() -> None:
+    called = []
+
+    def middleware_factory(app: ASGIApp) -> ASGIApp:
+        async def middleware(scope: Scope, receive: Receive, send: Send) -> None:
+            called.append(scope["method"])
+            await app(scope, receive, send)
+
+        return middleware
+
+    @get("/items")
+    def get_items() -> List[str]:
+        return []
+
+    @post("/items")
+    def create_item() -> None:
+        return None
+
+    cors_config = CORSConfig(allow_origins=["http://www.example.com"], allow_methods=["GET", "PUT"])
+    router = Router(
+        "/api",
+        route_handlers=[get_items, create_item],
+        middleware=[middleware_factory],
+        opt={"cors_config": cors_config},
+    )
+
+    with create_test_client(router) as client:
+        headers = {"Origin": "http://www.example.com", "Access-Control-Request-Method": "GET"}
+        response = client.options("/api/items", headers=headers)
+        assert response.status_code == HTTP_204_NO_CONTENT
+        assert response.headers["Access-Control-Allow-Origin"] == "http://www.example.com"
+        assert response.headers["Access-Control-Allow-Methods"] == "GET"
+
+        headers["Access-Control-Request-Method"] = "POST"
+        assert client.options("/api/items", headers=headers).status_code == HTTP_400_BAD_REQUEST
+
+        headers["Origin"] = "http://www.example.org"
+        headers["Access-Control-Request-Method"] = "GET"
+        assert client.options("/api/items", headers=headers).status_code == HTTP_400_BAD_REQUEST
+        assert called == []
+
+        assert client.options("/api/items").status_code == HTTP_204_NO_CONTENT
+        assert called == ["OPTIONS"]
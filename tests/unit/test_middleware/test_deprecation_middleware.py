@@ -0,0 +1,45 @@
+from datetime import date, datetime, timedelta, timezone
+
+from litestar import get
+from litestar.exceptions import NotAuthorizedException
+from litestar.status_codes import HTTP_401_UNAUTHORIZED
+from litestar.testing import create_test_client
+
+
+def test_deprecated_route_headers() -> None:
+    @get("/old", deprecated=True, sunset=date(2030, 1, 1), deprecation_link="https://example.org/migration")
+    def old_handler() -> str:
+        return "old"
+
+    @get("/new")
+    def new_handler() -> str:
+        return "new"
+
+    with create_test_client([old_handler, new_handler]) as client:
+        response = client.get("/old")
+        assert response.headers["deprecation"] == "true"
+        assert response.headers["sunset"] == "Tue, 01 Jan 2030 00:00:00 GMT"
+        assert response.headers["link"] == '<https://example.org/migration>; rel="deprecation"'
+
+        response = client.get("/new")
+        assert "deprecation" not in response.headers
+        assert "sunset" not in response.headers
+
+        client.get("/old")
+        route_handler = client.app.route_handler_method_map["/old"]["GET"]
+        assert client.app.asgi_router.deprecated_route_hits == {str(route_handler): 2}
+
+
+def test_deprecated_route_headers_on_error_response() -> None:
+    sunset = datetime(2030, 1, 1, 12, tzinfo=timezone(timedelta(hours=2)))
+
+    @get("/old", deprecated=True, sunset=sunset)
+    def old_handler() -> None:
+        raise NotAuthorizedException()
+
+    with create_test_client([old_handler]) as client:
+        response = client.get("/old")
+        assert response.status_code == HTTP_401_UNAUTHORIZED
+        assert response.headers["deprecation"] == "true"
+        assert response.headers["sunset"] == "Tue, 01 Jan 2030 10:00:00 GMT"
+        assert "link" not in response.headers
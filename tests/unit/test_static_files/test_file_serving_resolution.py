@@ -10,13 +10,16 @@ import pytest
 from typing_extensions import TypeAlias
 
 from litestar import MediaType, Router, get
-from litestar.static_files import StaticFilesConfig, create_static_files_router
-from litestar.status_codes import HTTP_200_OK
+from litestar.datastructures import CacheControlHeader
+from litestar.exceptions import NotFoundException
+from litestar.file_system import BaseLocalFileSystem
+from litestar.static_files import StaticFiles, StaticFilesConfig, create_static_files_router
+from litestar.status_codes import HTTP_200_OK, HTTP_304_NOT_MODIFIED, HTTP_404_NOT_FOUND
 from litestar.testing import create_test_client
 from tests.unit.test_static_files.conftest import MakeConfig
 
 if TYPE_CHECKING:
-    from litestar.types import FileSystemProtocol
+    from litestar.types import FileSystemProtocol, SymlinkPolicy
 
 
 def test_default_static_files_config(tmpdir: Path, make_config: MakeConfig) -> None:
@@ -295,3 +298,136 @@ def test_resolve_symlinks(tmp_path: Path, resolve: bool) -> None:
             assert client.get("/test.txt").status_code == 404
         else:
             assert client.get("/test.txt").status_code == 200
+
+
+@pytest.mark.parametrize(
+    "symlink_policy, expected_status_codes",
+    [
+        ("follow", (200, 200, 200)),
+        ("within_directory", (200, 404, 200)),
+        ("deny", (200, 404, 404)),
+    ],
+)
+def test_symlink_policy(
+    tmp_path: Path, symlink_policy: SymlinkPolicy, expected_status_codes: tuple[int, int, int]
+) -> None:
+    static_dir = tmp_path / "static"
+    static_dir.joinpath("sub").mkdir(parents=True)
+    static_dir.joinpath("test.txt").write_text("content")
+    outside_file = tmp_path / "secret.txt"
+    outside_file.write_text("secret")
+    static_dir.joinpath("outside.txt").symlink_to(outside_file)
+    static_dir.joinpath("sub", "inside.txt").symlink_to(static_dir / "test.txt")
+
+    router = create_static_files_router(path="/", directories=[static_dir], symlink_policy=symlink_policy)
+
+    with create_test_client(router) as client:
+        status_codes = tuple(client.get(path).status_code for path in ("/test.txt", "/outside.txt", "/sub/inside.txt"))
+        assert status_codes == expected_status_codes
+
+
+async def test_handle_does_not_escape_directories(tmp_path: Path) -> None:
+    static_dir = tmp_path / "static"
+    static_dir.mkdir()
+    tmp_path.joinpath("secret.txt").write_text("secret")
+    static_files = StaticFiles(is_html_mode=False, directories=[static_dir], file_system=BaseLocalFileSystem())
+
+    with pytest.raises(NotFoundException):
+        await static_files.handle(path="../secret.txt", is_head_response=False)
+
+
+def test_conditional_requests(tmp_path: Path) -> None:
+    tmp_path.joinpath("test.txt").write_text("content")
+    router = create_static_files_router(path="/static", directories=[tmp_path])
+
+    with create_test_client(router) as client:
+        response = client.get("/static/test.txt")
+        assert response.status_code == HTTP_200_OK
+        etag = response.headers["etag"]
+        last_modified = response.headers["last-modified"]
+
+        response = client.get("/static/test.txt", headers={"if-none-match": etag})
+        assert response.status_code == HTTP_304_NOT_MODIFIED
+        assert response.content == b""
+        assert response.headers["etag"] == etag
+
+        response = client.head("/static/test.txt", headers={"if-none-match": f'"other", W/{etag}'})
+        assert response.status_code == HTTP_304_NOT_MODIFIED
+
+        response = client.get("/static/test.txt", headers={"if-modified-since": last_modified})
+        assert response.status_code == HTTP_304_NOT_MODIFIED
+
+        response = client.get(
+            "/static/test.txt", headers={"if-none-match": '"other"', "if-modified-since": last_modified}
+        )
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "content"
+
+
+@pytest.mark.parametrize(
+    "accept_encoding, expected_encoding",
+    [("gzip, br", "br"), ("gzip", "gzip"), ("br;q=0, gzip", "gzip"), ("identity", None)],
+)
+def test_precompressed(tmp_path: Path, accept_encoding: str, expected_encoding: str | None) -> None:
+    tmp_path.joinpath("app.js").write_text("plain")
+    tmp_path.joinpath("app.js.br").write_bytes(brotli.compress(b"br"))
+    tmp_path.joinpath("app.js.gz").write_bytes(gzip.compress(b"gzip"))
+    router = create_static_files_router(path="/static", directories=[tmp_path], precompressed=True)
+
+    with create_test_client(router) as client:
+        response = client.get("/static/app.js", headers={"accept-encoding": accept_encoding})
+        assert response.status_code == HTTP_200_OK
+        assert response.headers.get("content-encoding") == expected_encoding
+        assert "javascript" in response.headers["content-type"]
+        assert response.headers["vary"] == "Accept-Encoding"
+        assert response.text == (expected_encoding or "plain")
+
+
+def test_directory_listing(tmp_path: Path) -> None:
+    tmp_path.joinpath("sub").mkdir()
+    tmp_path.joinpath("sub", "b.txt").write_text("b")
+    tmp_path.joinpath("a <1>.txt").write_text("a")
+
+    with create_test_client(create_static_files_router(path="/static", directories=[tmp_path])) as client:
+        assert client.get("/static/sub").status_code == HTTP_404_NOT_FOUND
+
+    router = create_static_files_router(path="/static", directories=[tmp_path], directory_listing=True)
+    with create_test_client(router) as client:
+        response = client.get("/static/")
+        assert response.status_code == HTTP_200_OK
+        assert response.headers["content-type"].startswith(MediaType.HTML)
+        assert response.text.index('href="/static/sub/"') < response.text.index('href="/static/a%20%3C1%3E.txt"')
+        assert "a &lt;1&gt;.txt" in response.text
+        assert "../" not in response.text
+
+        response = client.get("/static/sub")
+        assert 'href="/static/"' in response.text
+        assert 'href="/static/sub/b.txt"' in response.text
+
+        response = client.get("/static/sub", headers={"accept": MediaType.JSON})
+        assert response.headers["content-type"].startswith(MediaType.JSON)
+        assert response.json() == {
+            "path": "/static/sub",
+            "entries": [{"name": "b.txt", "type": "file", "size": 1, "mtime": response.json()["entries"][0]["mtime"]}],
+        }
+
+
+def test_cache_control_rules(tmp_path: Path) -> None:
+    tmp_path.joinpath("app.3f2a.js").write_text("app")
+    tmp_path.joinpath("test.txt").write_text("content")
+    router = create_static_files_router(
+        path="/static",
+        directories=[tmp_path],
+        cache_control=CacheControlHeader(max_age=60),
+        cache_control_rules={"*.js": CacheControlHeader(max_age=31536000, immutable=True)},
+    )
+
+    with create_test_client(router) as client:
+        response = client.get("/static/app.3f2a.js")
+        assert response.headers["cache-control"] == "max-age=31536000, immutable"
+
+        response = client.get("/static/app.3f2a.js", headers={"if-none-match": response.headers["etag"]})
+        assert response.status_code == HTTP_304_NOT_MODIFIED
+        assert response.headers["cache-control"] == "max-age=31536000, immutable"
+
+        assert client.get("/static/test.txt").headers["cache-control"] == "max-age=60"
@@ -0,0 +1,89 @@
+from __future__ import annotations
+
+from litestar import Litestar, get, post
+from litestar._asgi import TenantASGIRouter
+from litestar.config.versioning import VersioningConfig
+from litestar.status_codes import HTTP_200_OK, HTTP_201_CREATED, HTTP_404_NOT_FOUND
+from litestar.testing import TestClient
+
+
+@get("/shared")
+def shared_handler() -> str:
+    return "base"
+
+
+@get("/greeting")
+def base_greeting() -> str:
+    return "hello"
+
+
+@get("/greeting")
+def acme_greeting() -> str:
+    return "hello from acme"
+
+
+@post("/reports")
+def acme_reports() -> str:
+    return "report"
+
+
+def _create_apps() -> tuple[Litestar, dict[str, Litestar]]:
+    base = Litestar(route_handlers=[shared_handler, base_greeting], openapi_config=None)
+    return base, {"acme": Litestar(route_handlers=[acme_greeting, acme_reports], openapi_config=None)}
+
+
+def test_tenant_router_from_header() -> None:
+    base, tenants = _create_apps()
+    router = TenantASGIRouter.from_header(base=base, tenants=tenants, header_name="X-Tenant")
+
+    with TestClient(router) as client:
+        assert client.get("/greeting", headers={"X-Tenant": "acme"}).text == "hello from acme"
+        assert client.get("/greeting", headers={"X-Tenant": "other"}).text == "hello"
+        assert client.get("/greeting").text == "hello"
+        assert client.get("/shared", headers={"X-Tenant": "acme"}).text == "base"
+        assert client.post("/reports", headers={"X-Tenant": "acme"}).status_code == HTTP_201_CREATED
+        assert client.post("/reports").status_code == HTTP_404_NOT_FOUND
+
+
+def test_tenant_router_falls_back_on_method_mismatch() -> None:
+    base, tenants = _create_apps()
+    router = TenantASGIRouter.from_header(base=base, tenants=tenants, header_name="x-tenant")
+
+    with TestClient(router) as client:
+        assert client.get("/reports", headers={"x-tenant": "acme"}).status_code == HTTP_404_NOT_FOUND
+
+
+def test_tenant_router_from_host() -> None:
+    base, tenants = _create_apps()
+    router = TenantASGIRouter.from_host(base=base, tenants=tenants)
+
+    with TestClient(router, base_url="http://ACME:8000") as client:
+        response = client.get("/greeting")
+        assert response.status_code == HTTP_200_OK
+        assert response.text == "hello from acme"
+
+    with TestClient(router, base_url="http://example.org") as client:
+        assert client.get("/greeting").text == "hello"
+
+
+def test_tenant_router_with_versioned_tenant() -> None:
+    @get("/items")
+    def base_items() -> str:
+        return "base items"
+
+    @get("/items")
+    def acme_items() -> str:
+        return "acme items"
+
+    base = Litestar(route_handlers=[base_items], openapi_config=None)
+    tenant = Litestar(
+        route_handlers=[acme_items],
+        openapi_config=None,
+        versioning_config=VersioningConfig(versions=["v1", "v2"], path_prefix=True),
+    )
+    router = TenantASGIRouter.from_header(base=base, tenants={"acme": tenant}, header_name="X-Tenant")
+
+    with TestClient(router) as client:
+        assert client.get("/v2/items", headers={"X-Tenant": "acme"}).text == "acme items"
+        assert client.get("/items", headers={"X-Tenant": "acme"}).text == "acme items"
+        assert client.get("/v2/items").status_code == HTTP_404_NOT_FOUND
@@ -0,0 +1,64 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+from hypothesis import given, settings
+from hypothesis import strategies as st
+
+from litestar import Litestar, asgi
+from litestar.status_codes import HTTP_200_OK
+from litestar.testing import TestClient
+from litestar.testing.routing import generate_cases, generate_routes
+from litestar.utils.path import normalize_route_path
+
+if TYPE_CHECKING:
+    from litestar.types import Receive, Scope, Send
+
+
+async def _asgi_app(scope: Scope, receive: Receive, send: Send) -> None:
+    return None
+
+
+def test_generate_cases_is_deterministic() -> None:
+    assert generate_cases(seed=42, n=20) == generate_cases(seed=42, n=20)
+    assert generate_cases(seed=42, n=20) != generate_cases(seed=43, n=20)
+
+
+@given(seed=st.integers(min_value=0))
+@settings(deadline=None, max_examples=50)
+def test_registered_templates_match_their_rendered_paths(seed: int) -> None:
+    cases = generate_cases(seed=seed, n=10)
+    app = Litestar(route_handlers=[asgi(case.template)(_asgi_app) for case in cases], openapi_config=None)
+
+    for case in cases:
+        _, route_handler, _, path_params, _, _ = app.asgi_router.handle_routing(path=case.path, method="GET")
+        assert route_handler.paths == {case.template}, f"generate_cases(seed={seed}, n=10): {case}"
+        assert path_params == case.path_params, f"generate_cases(seed={seed}, n=10): {case}"
+
+
+@given(path=st.text())
+def test_route_path_normalization_is_idempotent(path: str) -> None:
+    normalized_path = normalize_route_path(path)
+    assert normalize_route_path(normalized_path) == normalized_path
+
+
+def test_generate_routes() -> None:
+    route_specs = generate_routes(seed=1, count=100, depth=3, params_ratio=0.5)
+
+    assert len({spec["path"] for spec in route_specs}) == 100
+    assert route_specs == generate_routes(seed=1, count=100, depth=3, params_ratio=0.5)
+
+    app = Litestar(openapi_config=None)
+    app.asgi_router.add_routes(route_specs)
+    assert app.asgi_router.self_check() == []
+
+    plain_spec = next(spec for spec in route_specs if "{" not in spec["path"])
+    with TestClient(app) as client:
+        response = client.request(plain_spec["methods"][0], plain_spec["path"])
+        assert response.status_code == HTTP_200_OK
+
+
+def test_generate_routes_exhausted() -> None:
+    with pytest.raises(ValueError):
+        generate_routes(seed=1, count=100, depth=1)
@@ -1,6 +1,6 @@
 import pytest
 
-from litestar.utils.path import join_paths, normalize_path
+from litestar.utils.path import canonicalize_path, join_paths, normalize_path, normalize_route_path
 
 
 @pytest.mark.parametrize(
@@ -45,3 +45,38 @@ def test_join_single() -> None:
 )
 def test_normalize_path(base: str, expected: str) -> None:
     assert normalize_path(base) == expected
+
+
+@pytest.mark.parametrize(
+    "base,expected",
+    [
+        ("", "/"),
+        ("/path/", "/path"),
+        ("//path//sub//", "/path/sub"),
+        (" /path ", "/path"),
+        ("/path/ /sub", "/path/sub"),
+        ("/path/ \t/", "/path"),
+        ("/path/a b", "/path/a b"),
+        ("/path/a /\t", "/path/a"),
+    ],
+)
+def test_normalize_route_path(base: str, expected: str) -> None:
+    assert normalize_route_path(base) == expected
+    assert normalize_route_path(expected) == expected
+
+
+@pytest.mark.parametrize(
+    "base,expected",
+    [
+        ("/", "/"),
+        ("/path//sub/", "/path/sub"),
+        ("/path/./sub", "/path/sub"),
+        ("/path/../sub", "/sub"),
+        ("/path/sub/..", "/path"),
+        ("/../path", "/path"),
+        ("/path/...", "/path/..."),
+    ],
+)
+def test_canonicalize_path(base: str, expected: str) -> None:
+    assert canonicalize_path(base) == expected
+    assert canonicalize_path(expected) == expected